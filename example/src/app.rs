@@ -4,14 +4,9 @@ use std::{
     sync::Arc,
 };
 
-use chrono::Timelike;
 use chrono_tz::Tz;
 use image::DynamicImage;
-use itertools::{
-    Itertools,
-    MinMaxResult::{MinMax, NoElements, OneElement},
-};
-use monsoon::{body::TimeSeries, Monsoon, Response};
+use monsoon::{Monsoon, Response};
 use parking_lot::RwLock;
 use tokio::sync::mpsc::{self, Receiver, Sender};
 
@@ -88,95 +83,31 @@ impl Location {
 fn format_forecast(tz: Tz, response: &Response) -> Vec<VecDeque<Option<String>>> {
     let body = response.body().expect("Properly formatted body");
 
-    body.properties
-        .timeseries
-        .iter()
-        .group_by(|timeseries| timeseries.time.with_timezone(&tz).date_naive())
+    body.daily(&tz)
         .into_iter()
         .take(7)
-        .enumerate()
-        .map(|(idx, (day, hours))| {
-            let hours: Vec<_> = hours.collect();
-
-            let mut row = VecDeque::new();
-            pick_symbols_from_hours(&mut row, idx, &hours);
-            max_min_temperature(&mut row, &hours);
-            precipitation(&mut row, &hours);
-            wind(&mut row, &hours);
-            row.push_front(Some(day.format("%A, %-d %B").to_string()));
-
-            row
-        })
-        .collect()
-}
-
-fn pick_symbols_from_hours(row: &mut VecDeque<Option<String>>, idx: usize, hours: &[&TimeSeries]) {
-    for hour in hours {
-        if [0, 6, 12, 18].contains(&hour.time.hour()) {
+        .map(|day| {
+            let mut row: VecDeque<_> = day
+                .symbols
+                .iter()
+                .map(|symbol| symbol.as_ref().map(ToString::to_string))
+                .collect();
+
+            row.push_front(Some(day.date.format("%A, %-d %B").to_string()));
             row.push_back(
-                hour.data
-                    .next_6_hours
-                    .as_ref()
-                    .map(|next| next.summary.symbol_code.to_owned()),
+                day.air_temperature_max
+                    .zip(day.air_temperature_min)
+                    .map(|(max, min)| format!("{}° / {}°", max, min)),
             );
-        }
-    }
-
-    // The first day may not start at 0
-    if idx == 0 && row.len() != 4 {
-        row.push_front(
-            hours[0]
-                .data
-                .next_6_hours
-                .as_ref()
-                .map(|next| next.summary.symbol_code.to_owned()),
-        );
-    }
+            row.push_back(Some(format!(
+                "{:.1}",
+                day.precipitation_amount.unwrap_or_default()
+            )));
+            row.push_back(day.wind_speed_max.map(|max| format!("{:.1}", max)));
 
-    for _ in row.len()..4 {
-        row.push_front(None);
-    }
-}
-
-fn max_min_temperature(row: &mut VecDeque<Option<String>>, hours: &[&TimeSeries]) {
-    match hours
-        .iter()
-        .filter_map(|hour| hour.data.instant.details.air_temperature)
-        .minmax()
-    {
-        NoElements => {}
-        OneElement(one) => row.push_back(format!("{}° / {}°", one, one).into()),
-        MinMax(min, max) => row.push_back(format!("{}° / {}°", max, min).into()),
-    };
-}
-
-fn precipitation(row: &mut VecDeque<Option<String>>, hours: &[&TimeSeries]) {
-    // This is not what yr.no does but good enough
-    let total: f64 = hours
-        .iter()
-        .filter_map(|hour| {
-            hour.data
-                .next_6_hours
-                .as_ref()?
-                .details
-                .as_ref()?
-                .precipitation_amount
+            row
         })
-        .sum();
-
-    row.push_back(format!("{:.1}", total).into());
-}
-
-fn wind(row: &mut VecDeque<Option<String>>, hours: &[&TimeSeries]) {
-    if let Some(max) = hours
-        .iter()
-        .filter_map(|hour| hour.data.instant.details.wind_speed)
-        .reduce(f64::max)
-    {
-        row.push_back(format!("{:.1}", max).into());
-    } else {
-        row.push_back(None);
-    }
+        .collect()
 }
 
 pub struct App {
@@ -1,20 +1,20 @@
 use std::{error::Error, time::Duration};
 
-use monsoon::{Monsoon, Params};
-use tower::{Service, ServiceBuilder, ServiceExt};
+use monsoon::{Monsoon, Params, RateLimit};
+use tower::{Service, ServiceExt};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
-    let monsoon = Monsoon::new("test.com support@test.com")?;
+    let mut monsoon = Monsoon::builder("test.com support@test.com")
+        .rate_limit(
+            // At most 20 requests per second
+            RateLimit::new(20, Duration::from_secs(1))
+                // At most 50 requests in-flight at the same time
+                .concurrency(50),
+        )
+        .build()?;
 
-    let mut service = ServiceBuilder::new()
-        // At most 50 requests in-flight at the same time
-        .concurrency_limit(50)
-        // At most 20 requests per second
-        .rate_limit(20, Duration::from_secs(1))
-        .service(monsoon);
-
-    let response = service
+    let response = monsoon
         .ready()
         .await?
         .call(Params::new(50.0880, 14.4207, None)?)
@@ -1,6 +1,6 @@
 use cli_table::{format::Justify, print_stdout, Cell, CellStruct, Style, Table};
 
-use monsoon::Monsoon;
+use monsoon::{body::Unit, Monsoon};
 use std::{error::Error, result::Result};
 
 fn to_cell(val: Option<f64>) -> CellStruct {
@@ -37,13 +37,23 @@ async fn main() -> Result<(), Box<dyn Error>> {
             "Date & Time".cell().bold(true),
             format!(
                 "Air Temperature ({})",
-                body.properties.meta.units.air_temperature.unwrap_or("??")
+                body.properties
+                    .meta
+                    .units
+                    .air_temperature
+                    .as_ref()
+                    .map_or("??", Unit::symbol)
             )
             .cell()
             .bold(true),
             format!(
                 "Wind Speed ({})",
-                body.properties.meta.units.wind_speed.unwrap_or("??")
+                body.properties
+                    .meta
+                    .units
+                    .wind_speed
+                    .as_ref()
+                    .map_or("??", Unit::symbol)
             )
             .cell()
             .bold(true),
@@ -0,0 +1,451 @@
+//! Forecast of the air quality (AQI and concentrations of the pollutants) as provided by the
+//! [Air quality forecast API]. It's available only for Norway. The forecast can be looked up for
+//! coordinates, a measuring station or an area. Use [Monsoon::get_air_quality_stations] and
+//! [Monsoon::get_air_quality_areas] to list the available stations and areas.
+//!
+//! Example:
+//!
+//! ```no_run
+//! use monsoon::{airquality, Monsoon};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+//! let monsoon = Monsoon::new("test.com support@test.com")?;
+//!
+//! let params = airquality::Params::point(59.9139, 10.7522)?;
+//! let body = monsoon.get_air_quality(params).await?.air_quality_body()?;
+//!
+//! for time in body.data.time.iter() {
+//!     println!("{}: {:?}", time.from, time.variables.level());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [Air quality forecast API]: https://api.met.no/weatherapi/airqualityforecast/0.1/documentation
+//! [Monsoon::get_air_quality_stations]: crate::Monsoon::get_air_quality_stations
+//! [Monsoon::get_air_quality_areas]: crate::Monsoon::get_air_quality_areas
+use std::borrow::Cow;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer};
+
+use crate::{body::Unit, client::Request, monsoon::normalize_coordinates, Error, Response, Result};
+
+/// The location for which the forecast should be looked up.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Params {
+    pub location: Location,
+
+    pub last_response: Option<Response>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Location {
+    Point {
+        lat: f64,
+        lon: f64,
+    },
+    /// The EOI code of a measuring station, e.g. "NO0057A" (see [Station::eoi]).
+    Station(Box<str>),
+    /// The code of an area, e.g. "0301" for Oslo (see [Area::areacode]).
+    Area(Box<str>),
+}
+
+impl Params {
+    /// Creates a new Params instance for the given coordinates. They're validated and normalized
+    /// the same way as in [crate::Params::new].
+    pub fn point(lat: f64, lon: f64) -> Result<Self> {
+        let (lat, lon) = normalize_coordinates(lat, lon)?;
+
+        Ok(Self {
+            location: Location::Point { lat, lon },
+            last_response: None,
+        })
+    }
+
+    /// Creates a new Params instance for the given measuring station.
+    pub fn station(eoi: &str) -> Result<Self> {
+        if eoi.is_empty() || !eoi.bytes().all(|byte| byte.is_ascii_alphanumeric()) {
+            return Err(Error::Params("Invalid station value."));
+        }
+
+        Ok(Self {
+            location: Location::Station(eoi.into()),
+            last_response: None,
+        })
+    }
+
+    /// Creates a new Params instance for the given area.
+    pub fn area(areacode: &str) -> Result<Self> {
+        if areacode.is_empty() || !areacode.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(Error::Params("Invalid area value."));
+        }
+
+        Ok(Self {
+            location: Location::Area(areacode.into()),
+            last_response: None,
+        })
+    }
+}
+
+impl Request for Params {
+    fn path(&self) -> &'static str {
+        "airqualityforecast/0.1/"
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        match &self.location {
+            Location::Point { lat, lon } => {
+                vec![("lat", lat.to_string()), ("lon", lon.to_string())]
+            }
+            Location::Station(eoi) => vec![("station", eoi.to_string())],
+            Location::Area(areacode) => vec![("areacode", areacode.to_string())],
+        }
+    }
+
+    fn last_response(&self) -> Option<&Response> {
+        self.last_response.as_ref()
+    }
+}
+
+/// Administrative level of the areas listed by [Monsoon::get_air_quality_areas].
+///
+/// [Monsoon::get_air_quality_areas]: crate::Monsoon::get_air_quality_areas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AreaClass {
+    /// Fylke
+    County,
+    /// Kommune
+    Municipality,
+    /// Delområde
+    District,
+    /// Grunnkrets
+    BasicUnit,
+}
+
+impl AreaClass {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AreaClass::County => "fylke",
+            AreaClass::Municipality => "kommune",
+            AreaClass::District => "delomrade",
+            AreaClass::BasicUnit => "grunnkrets",
+        }
+    }
+}
+
+/// Request for the list of areas.
+#[derive(Debug)]
+pub(crate) struct AreasRequest {
+    pub class: AreaClass,
+}
+
+impl Request for AreasRequest {
+    fn path(&self) -> &'static str {
+        "airqualityforecast/0.1/areas"
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        vec![("areaclass", self.class.as_str().to_string())]
+    }
+
+    fn last_response(&self) -> Option<&Response> {
+        None
+    }
+}
+
+/// Request for the list of measuring stations.
+#[derive(Debug)]
+pub(crate) struct StationsRequest;
+
+impl Request for StationsRequest {
+    fn path(&self) -> &'static str {
+        "airqualityforecast/0.1/stations"
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    fn last_response(&self) -> Option<&Response> {
+        None
+    }
+}
+
+impl Response {
+    /// Parses the body of a response from [Monsoon::get_air_quality].
+    ///
+    /// [Monsoon::get_air_quality]: crate::Monsoon::get_air_quality
+    pub fn air_quality_body(&self) -> Result<Body> {
+        serde_json::from_str(&self.raw_body).map_err(Into::into)
+    }
+
+    /// Parses the body of a response from [Monsoon::get_air_quality_areas].
+    ///
+    /// [Monsoon::get_air_quality_areas]: crate::Monsoon::get_air_quality_areas
+    pub fn air_quality_areas(&self) -> Result<Box<[Area]>> {
+        serde_json::from_str(&self.raw_body).map_err(Into::into)
+    }
+
+    /// Parses the body of a response from [Monsoon::get_air_quality_stations].
+    ///
+    /// [Monsoon::get_air_quality_stations]: crate::Monsoon::get_air_quality_stations
+    pub fn air_quality_stations(&self) -> Result<Box<[Station]>> {
+        serde_json::from_str(&self.raw_body).map_err(Into::into)
+    }
+}
+
+/// Response body of the Air quality forecast API as defined in the [`documentation`].
+///
+/// [`documentation`]: https://api.met.no/weatherapi/airqualityforecast/0.1/documentation
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Body {
+    pub data: Data,
+    pub meta: Meta,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Data {
+    pub time: Box<[Time]>,
+}
+
+/// The forecast for a single time interval.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Time {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub variables: Variables,
+}
+
+/// Concentrations are in μg/m³. The AQI values are on the scale described in [Level].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Variables {
+    #[serde(rename = "AQI")]
+    pub aqi: Option<Variable>,
+    #[serde(rename = "AQI_no2")]
+    pub aqi_no2: Option<Variable>,
+    #[serde(rename = "AQI_o3")]
+    pub aqi_o3: Option<Variable>,
+    #[serde(rename = "AQI_pm10")]
+    pub aqi_pm10: Option<Variable>,
+    #[serde(rename = "AQI_pm25")]
+    pub aqi_pm25: Option<Variable>,
+    pub no2_concentration: Option<Variable>,
+    pub o3_concentration: Option<Variable>,
+    pub pm10_concentration: Option<Variable>,
+    pub pm25_concentration: Option<Variable>,
+}
+
+impl Variables {
+    /// Returns the level of the overall air quality index.
+    pub fn level(&self) -> Option<Level> {
+        self.aqi.as_ref().map(|aqi| Level::from_aqi(aqi.value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Variable {
+    pub value: f64,
+    pub units: Unit,
+}
+
+/// Level of the air quality as used by the Norwegian authorities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    /// AQI below 2.
+    Low,
+    /// AQI from 2 to 3.
+    Moderate,
+    /// AQI from 3 to 4.
+    High,
+    /// AQI of 4 and more.
+    VeryHigh,
+}
+
+impl Level {
+    pub fn from_aqi(aqi: f64) -> Self {
+        if aqi < 2.0 {
+            Level::Low
+        } else if aqi < 3.0 {
+            Level::Moderate
+        } else if aqi < 4.0 {
+            Level::High
+        } else {
+            Level::VeryHigh
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Meta {
+    pub reftime: DateTime<Utc>,
+    pub location: MetaLocation,
+    pub superlocation: Option<MetaLocation>,
+    #[serde(default)]
+    pub sublocations: Box<[MetaLocation]>,
+}
+
+/// The location the forecast was made for.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MetaLocation {
+    pub name: Box<str>,
+    pub path: Option<Box<str>>,
+    pub areacode: Option<Box<str>>,
+    #[serde(default, deserialize_with = "deserialize_number")]
+    pub latitude: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_number")]
+    pub longitude: Option<f64>,
+}
+
+/// Area for which the forecast is available, see [Location::Area].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Area {
+    pub areacode: Box<str>,
+    pub name: Box<str>,
+    pub areaclass: Option<Box<str>>,
+    pub superareacode: Option<Box<str>>,
+    #[serde(default, deserialize_with = "deserialize_number")]
+    pub latitude: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_number")]
+    pub longitude: Option<f64>,
+}
+
+/// Measuring station for which the forecast is available, see [Location::Station].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Station {
+    pub eoi: Box<str>,
+    pub name: Box<str>,
+    #[serde(default, deserialize_with = "deserialize_number")]
+    pub latitude: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_number")]
+    pub longitude: Option<f64>,
+    /// Altitude in meters.
+    #[serde(default, deserialize_with = "deserialize_number")]
+    pub height: Option<f64>,
+    pub kommune: Option<StationArea>,
+    pub delomrade: Option<StationArea>,
+    pub grunnkrets: Option<StationArea>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct StationArea {
+    pub areacode: Box<str>,
+    pub name: Box<str>,
+}
+
+/// The coordinates are sometimes sent as strings.
+fn deserialize_number<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<f64>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Number<'a> {
+        Number(f64),
+        #[serde(borrow)]
+        String(Cow<'a, str>),
+    }
+
+    match Option::<Number>::deserialize(deserializer)? {
+        Some(Number::Number(value)) => Ok(Some(value)),
+        Some(Number::String(value)) if value.is_empty() => Ok(None),
+        Some(Number::String(value)) => value
+            .parse()
+            .map(Some)
+            .map_err(|_| serde::de::Error::custom(format!("invalid number: {}", value))),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AreaClass, AreasRequest, Body, Level, Params, Station};
+    use crate::{client::Request, Error};
+
+    #[test]
+    fn creates_query() {
+        let params = Params::point(59.91391, 10.7522).unwrap();
+        assert_eq!(params.path(), "airqualityforecast/0.1/");
+        assert_eq!(
+            params.query(),
+            [
+                ("lat", "59.9139".to_string()),
+                ("lon", "10.7522".to_string())
+            ]
+        );
+
+        let params = Params::station("NO0057A").unwrap();
+        assert_eq!(params.query(), [("station", "NO0057A".to_string())]);
+
+        let request = AreasRequest {
+            class: AreaClass::Municipality,
+        };
+        assert_eq!(request.query(), [("areaclass", "kommune".to_string())]);
+
+        assert!(matches!(Params::area("03&x=1"), Err(Error::Params(_))));
+        assert!(matches!(Params::station(""), Err(Error::Params(_))));
+    }
+
+    #[test]
+    fn parses_body() {
+        let body: Body = serde_json::from_str(
+            r#"{
+                "data": {
+                    "time": [{
+                        "from": "2023-03-21T09:00:00Z",
+                        "to": "2023-03-21T10:00:00Z",
+                        "variables": {
+                            "AQI": { "value": 2.4, "units": "1" },
+                            "no2_concentration": { "value": 41.2, "units": "ug/m3" }
+                        }
+                    }]
+                },
+                "meta": {
+                    "reftime": "2023-03-21T00:00:00Z",
+                    "location": {
+                        "name": "Oslo",
+                        "path": "/Norge/Oslo",
+                        "areacode": "0301",
+                        "latitude": "59.9139",
+                        "longitude": "10.7522"
+                    },
+                    "sublocations": []
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let variables = &body.data.time[0].variables;
+        assert_eq!(variables.level(), Some(Level::Moderate));
+        assert_eq!(variables.no2_concentration.as_ref().unwrap().value, 41.2);
+        assert_eq!(body.meta.location.latitude, Some(59.9139));
+    }
+
+    #[test]
+    fn parses_stations() {
+        let stations: Box<[Station]> = serde_json::from_str(
+            r#"[{
+                "eoi": "NO0057A",
+                "name": "Kirkeveien",
+                "latitude": 59.932,
+                "longitude": "10.724",
+                "height": "",
+                "kommune": { "areacode": "0301", "name": "Oslo" }
+            }]"#,
+        )
+        .unwrap();
+
+        assert_eq!(&*stations[0].eoi, "NO0057A");
+        assert_eq!(stations[0].longitude, Some(10.724));
+        assert_eq!(stations[0].height, None);
+    }
+
+    #[test]
+    fn converts_aqi_to_level() {
+        assert_eq!(Level::from_aqi(1.0), Level::Low);
+        assert_eq!(Level::from_aqi(2.0), Level::Moderate);
+        assert_eq!(Level::from_aqi(3.9), Level::High);
+        assert_eq!(Level::from_aqi(5.2), Level::VeryHigh);
+    }
+}
@@ -0,0 +1,120 @@
+//! Synchronous API for use outside of an async runtime, e.g. in small scripts or build scripts.
+//! Requires the `blocking` feature.
+//!
+//! Example:
+//!
+//! ```no_run
+//! use monsoon::blocking::Monsoon;
+//!
+//! # fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+//! let monsoon = Monsoon::new("test.com support@test.com")?;
+//! let response = monsoon.get(50.0880, 14.4207)?;
+//! let body = response.body()?;
+//! # Ok(())
+//! # }
+//! ```
+use std::{borrow::Cow, sync::Arc};
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::{Error, MonsoonBuilder, Params, Response, Result};
+
+/// Blocking counterpart of [crate::Monsoon]. The requests are executed on an internal
+/// single-threaded Tokio runtime which is shared by all clones of the instance.
+///
+/// The methods must not be called from within an async runtime. Use [crate::Monsoon] there
+/// instead.
+#[derive(Debug, Clone)]
+pub struct Monsoon {
+    inner: crate::Monsoon,
+    runtime: Arc<Runtime>,
+}
+
+impl Monsoon {
+    /// Creates a new instance with the given user agent.
+    ///
+    /// Example:
+    ///
+    ///```no_run
+    ///use monsoon::blocking::Monsoon;
+    ///
+    ///let monsoon = Monsoon::new("test.com support@test.com");
+    ///```
+    pub fn new(user_agent: impl Into<Cow<'static, str>>) -> Result<Self> {
+        crate::Monsoon::builder(user_agent).build_blocking()
+    }
+
+    /// Creates a new builder with the given user agent. Use it to configure the instance beyond
+    /// the defaults and finish it with [MonsoonBuilder::build_blocking].
+    ///
+    /// Example:
+    ///
+    ///```no_run
+    ///use monsoon::blocking::Monsoon;
+    ///
+    ///let monsoon = Monsoon::builder("test.com support@test.com")
+    ///    .cache(100)
+    ///    .build_blocking();
+    ///```
+    pub fn builder(user_agent: impl Into<Cow<'static, str>>) -> MonsoonBuilder {
+        crate::Monsoon::builder(user_agent)
+    }
+
+    pub(crate) fn from_async(inner: crate::Monsoon) -> Result<Self> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(Error::Runtime)?;
+
+        Ok(Self {
+            inner,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Fetches weather data for the given coordinates. See [crate::Monsoon::get].
+    pub fn get(&self, lat: f64, lon: f64) -> Result<Response> {
+        self.runtime.block_on(self.inner.get(lat, lon))
+    }
+
+    /// Fetches weather data for the given coordinates including altitude. See
+    /// [crate::Monsoon::get_with_altitude].
+    pub fn get_with_altitude(&self, lat: f64, lon: f64, alt: i32) -> Result<Response> {
+        self.runtime
+            .block_on(self.inner.get_with_altitude(lat, lon, alt))
+    }
+
+    /// Fetches weather data for the given coordinates provided via Params. See
+    /// [crate::Monsoon::get_with_params].
+    pub fn get_with_params(&self, params: Params) -> Result<Response> {
+        self.runtime.block_on(self.inner.get_with_params(params))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Monsoon;
+    use crate::{
+        transport::{
+            fake::{headers, FakeTransport},
+            StatusCode,
+        },
+        Error,
+    };
+
+    #[test]
+    fn runs_without_async_runtime() {
+        let transport = FakeTransport::default().respond(
+            StatusCode::OK,
+            headers("Tue, 21 Mar 2023 09:30:00 GMT"),
+            "{}",
+        );
+        let monsoon = Monsoon::builder("test.com support@test.com")
+            .transport(transport)
+            .build_blocking()
+            .unwrap();
+
+        assert!(matches!(monsoon.get(91.0, 14.4207), Err(Error::Params(_))));
+        assert_eq!(&*monsoon.get(50.0880, 14.4207).unwrap().raw_body, "{}");
+    }
+}
@@ -1,87 +1,126 @@
+use std::borrow::Cow;
+
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
+pub mod compact;
+mod daily;
+mod derived;
+mod interpolation;
+mod periods;
+mod symbol;
+mod units;
+mod wind;
+
+pub use daily::DailySummary;
+pub use periods::{Interval, Period, Periods, Resolution};
+pub use symbol::{Condition, Symbol, Variant};
+pub use units::{
+    Density, DensityUnit, Length, LengthUnit, Pressure, PressureUnit, Speed, SpeedUnit,
+    Temperature, TemperatureUnit, Unit,
+};
+pub use wind::{Beaufort, CompassPoints, Wind};
+
 /// Response body from the "complete" API as defined in the [`documentation`]. Head over there to
 /// learn more about the individual fields if necessary.
 ///
 /// [`documentation`]: https://api.met.no/weatherapi/locationforecast/2.0/documentation
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct Body<'a> {
-    #[serde(rename(deserialize = "type"))]
-    pub type_field: &'a str,
+    #[serde(rename(deserialize = "type"), borrow)]
+    pub type_field: Cow<'a, str>,
     pub geometry: Geometry<'a>,
-    pub properties: Properties<'a>,
+    pub properties: Properties,
+}
+
+impl Body<'_> {
+    /// Converts the body into one which doesn't borrow from the [Response] so that it can be
+    /// stored or sent elsewhere independently of it.
+    ///
+    /// [Response]: crate::Response
+    pub fn into_owned(self) -> Body<'static> {
+        Body {
+            type_field: Cow::Owned(self.type_field.into_owned()),
+            geometry: self.geometry.into_owned(),
+            properties: self.properties,
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct Geometry<'a> {
-    #[serde(rename(deserialize = "type"))]
-    pub type_field: &'a str,
+    #[serde(rename(deserialize = "type"), borrow)]
+    pub type_field: Cow<'a, str>,
     pub coordinates: Coordinates,
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
+impl Geometry<'_> {
+    pub fn into_owned(self) -> Geometry<'static> {
+        Geometry {
+            type_field: Cow::Owned(self.type_field.into_owned()),
+            coordinates: self.coordinates,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct Coordinates {
     pub longitude: f64,
     pub latitude: f64,
     pub altitude: f64,
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
-#[serde(bound(deserialize = "'de: 'a"))]
-pub struct Properties<'a> {
-    pub meta: Meta<'a>,
-    pub timeseries: Box<[TimeSeries<'a>]>,
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Properties {
+    pub meta: Meta,
+    pub timeseries: Box<[TimeSeries]>,
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
-#[serde(bound(deserialize = "'de: 'a"))]
-pub struct Meta<'a> {
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Meta {
     pub updated_at: DateTime<Utc>,
-    pub units: Units<'a>,
-}
-
-#[derive(Debug, PartialEq, Deserialize)]
-pub struct Units<'a> {
-    pub air_pressure_at_sea_level: Option<&'a str>,
-    pub air_temperature: Option<&'a str>,
-    pub air_temperature_max: Option<&'a str>,
-    pub air_temperature_min: Option<&'a str>,
-    pub cloud_area_fraction: Option<&'a str>,
-    pub cloud_area_fraction_high: Option<&'a str>,
-    pub cloud_area_fraction_low: Option<&'a str>,
-    pub cloud_area_fraction_medium: Option<&'a str>,
-    pub dew_point_temperature: Option<&'a str>,
-    pub fog_area_fraction: Option<&'a str>,
-    pub precipitation_amount: Option<&'a str>,
-    pub relative_humidity: Option<&'a str>,
-    pub ultraviolet_index_clear_sky: Option<&'a str>,
-    pub wind_from_direction: Option<&'a str>,
-    pub wind_speed: Option<&'a str>,
-}
-
-#[derive(Debug, PartialEq, Deserialize)]
-#[serde(bound(deserialize = "'de: 'a"))]
-pub struct TimeSeries<'a> {
+    pub units: Units,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Units {
+    pub air_pressure_at_sea_level: Option<Unit>,
+    pub air_temperature: Option<Unit>,
+    pub air_temperature_max: Option<Unit>,
+    pub air_temperature_min: Option<Unit>,
+    pub cloud_area_fraction: Option<Unit>,
+    pub cloud_area_fraction_high: Option<Unit>,
+    pub cloud_area_fraction_low: Option<Unit>,
+    pub cloud_area_fraction_medium: Option<Unit>,
+    pub dew_point_temperature: Option<Unit>,
+    pub fog_area_fraction: Option<Unit>,
+    pub precipitation_amount: Option<Unit>,
+    pub relative_humidity: Option<Unit>,
+    pub ultraviolet_index_clear_sky: Option<Unit>,
+    pub wind_from_direction: Option<Unit>,
+    pub wind_speed: Option<Unit>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TimeSeries {
     pub time: DateTime<Utc>,
-    pub data: Data<'a>,
+    pub data: Data,
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
-#[serde(bound(deserialize = "'de: 'a"))]
-pub struct Data<'a> {
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Data {
     pub instant: Instant,
-    pub next_12_hours: Option<NextHours<'a>>,
-    pub next_1_hours: Option<NextHours<'a>>,
-    pub next_6_hours: Option<NextHours<'a>>,
+    pub next_12_hours: Option<NextHours>,
+    pub next_1_hours: Option<NextHours>,
+    pub next_6_hours: Option<NextHours>,
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct Instant {
     pub details: InstantDetails,
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct InstantDetails {
     pub air_pressure_at_sea_level: Option<f64>,
     pub air_temperature: Option<f64>,
@@ -97,15 +136,47 @@ pub struct InstantDetails {
     pub wind_speed: Option<f64>,
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
-#[serde(bound(deserialize = "'de: 'a"))]
-pub struct NextHours<'a> {
+impl InstantDetails {
+    pub fn air_pressure_at_sea_level_with_unit(&self, units: &Units) -> Option<Pressure> {
+        with_unit(
+            self.air_pressure_at_sea_level,
+            &units.air_pressure_at_sea_level,
+            Unit::pressure,
+            Pressure::new,
+        )
+    }
+
+    pub fn air_temperature_with_unit(&self, units: &Units) -> Option<Temperature> {
+        with_unit(
+            self.air_temperature,
+            &units.air_temperature,
+            Unit::temperature,
+            Temperature::new,
+        )
+    }
+
+    pub fn dew_point_temperature_with_unit(&self, units: &Units) -> Option<Temperature> {
+        with_unit(
+            self.dew_point_temperature,
+            &units.dew_point_temperature,
+            Unit::temperature,
+            Temperature::new,
+        )
+    }
+
+    pub fn wind_speed_with_unit(&self, units: &Units) -> Option<Speed> {
+        with_unit(self.wind_speed, &units.wind_speed, Unit::speed, Speed::new)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct NextHours {
     // Not optional in the docs but the API doesn't return it in all cases.
     pub details: Option<SummaryDetails>,
-    pub summary: Summary<'a>,
+    pub summary: Summary,
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct SummaryDetails {
     pub air_temperature_max: Option<f64>,
     pub air_temperature_min: Option<f64>,
@@ -117,7 +188,125 @@ pub struct SummaryDetails {
     pub ultraviolet_index_clear_sky_max: Option<f64>,
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
-pub struct Summary<'a> {
-    pub symbol_code: &'a str,
+impl SummaryDetails {
+    pub fn air_temperature_max_with_unit(&self, units: &Units) -> Option<Temperature> {
+        with_unit(
+            self.air_temperature_max,
+            &units.air_temperature_max,
+            Unit::temperature,
+            Temperature::new,
+        )
+    }
+
+    pub fn air_temperature_min_with_unit(&self, units: &Units) -> Option<Temperature> {
+        with_unit(
+            self.air_temperature_min,
+            &units.air_temperature_min,
+            Unit::temperature,
+            Temperature::new,
+        )
+    }
+
+    pub fn precipitation_amount_with_unit(&self, units: &Units) -> Option<Length> {
+        with_unit(
+            self.precipitation_amount,
+            &units.precipitation_amount,
+            Unit::length,
+            Length::new,
+        )
+    }
+
+    // The units don't list precipitation_amount_max and precipitation_amount_min separately.
+
+    pub fn precipitation_amount_max_with_unit(&self, units: &Units) -> Option<Length> {
+        with_unit(
+            self.precipitation_amount_max,
+            &units.precipitation_amount,
+            Unit::length,
+            Length::new,
+        )
+    }
+
+    pub fn precipitation_amount_min_with_unit(&self, units: &Units) -> Option<Length> {
+        with_unit(
+            self.precipitation_amount_min,
+            &units.precipitation_amount,
+            Unit::length,
+            Length::new,
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Summary {
+    pub symbol_code: Symbol,
+}
+
+/// Attaches the unit to the value if both are present and the unit is of the expected kind.
+pub(crate) fn with_unit<U, T>(
+    value: Option<f64>,
+    unit: &Option<Unit>,
+    kind: impl FnOnce(&Unit) -> Option<U>,
+    new: impl FnOnce(f64, U) -> T,
+) -> Option<T> {
+    Some(new(value?, kind(unit.as_ref()?)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Body, Condition, Symbol, Temperature, TemperatureUnit, Unit, Variant};
+
+    fn parse_owned(raw: &str) -> Body<'static> {
+        serde_json::from_str::<Body>(raw).unwrap().into_owned()
+    }
+
+    #[test]
+    fn converts_into_owned_body() {
+        let raw = String::from(
+            r#"{
+                "type": "Feature",
+                "geometry": { "type": "Point", "coordinates": [14.4207, 50.088, 210] },
+                "properties": {
+                    "meta": {
+                        "updated_at": "2023-03-21T09:00:00Z",
+                        "units": { "air_temperature": "celsius" }
+                    },
+                    "timeseries": [{
+                        "time": "2023-03-21T10:00:00Z",
+                        "data": {
+                            "instant": { "details": { "air_temperature": 8.1 } },
+                            "next_1_hours": { "summary": { "symbol_code": "fair_night" } }
+                        }
+                    }]
+                }
+            }"#,
+        );
+
+        let body = parse_owned(&raw);
+        drop(raw);
+
+        let properties = &body.properties;
+        assert_eq!(
+            properties.meta.units.air_temperature,
+            Some(Unit::Temperature(TemperatureUnit::Celsius))
+        );
+        assert_eq!(
+            properties.timeseries[0]
+                .data
+                .instant
+                .details
+                .air_temperature_with_unit(&properties.meta.units),
+            Some(Temperature::new(8.1, TemperatureUnit::Celsius))
+        );
+        assert_eq!(
+            properties.timeseries[0]
+                .data
+                .next_1_hours
+                .as_ref()
+                .unwrap()
+                .summary
+                .symbol_code,
+            Symbol::new(Condition::Fair, Variant::Night)
+        );
+    }
 }
@@ -0,0 +1,147 @@
+//! Response body from the "compact" API. It's a subset of the "complete" API covering the most
+//! commonly used values and as such is considerably smaller.
+use std::borrow::Cow;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use super::{Geometry, Meta, Summary};
+
+/// Response body from the "compact" API as defined in the [`documentation`].
+///
+/// [`documentation`]: https://api.met.no/weatherapi/locationforecast/2.0/documentation
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Body<'a> {
+    #[serde(rename(deserialize = "type"), borrow)]
+    pub type_field: Cow<'a, str>,
+    pub geometry: Geometry<'a>,
+    pub properties: Properties,
+}
+
+impl Body<'_> {
+    /// Converts the body into one which doesn't borrow from the [Response] so that it can be
+    /// stored or sent elsewhere independently of it.
+    ///
+    /// [Response]: crate::Response
+    pub fn into_owned(self) -> Body<'static> {
+        Body {
+            type_field: Cow::Owned(self.type_field.into_owned()),
+            geometry: self.geometry.into_owned(),
+            properties: self.properties,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Properties {
+    pub meta: Meta,
+    pub timeseries: Box<[TimeSeries]>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TimeSeries {
+    pub time: DateTime<Utc>,
+    pub data: Data,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Data {
+    pub instant: Instant,
+    pub next_12_hours: Option<NextHours>,
+    pub next_1_hours: Option<NextHours>,
+    pub next_6_hours: Option<NextHours>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Instant {
+    pub details: InstantDetails,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct InstantDetails {
+    pub air_pressure_at_sea_level: Option<f64>,
+    pub air_temperature: Option<f64>,
+    pub cloud_area_fraction: Option<f64>,
+    pub relative_humidity: Option<f64>,
+    pub wind_from_direction: Option<f64>,
+    pub wind_speed: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct NextHours {
+    // Never present for next_12_hours.
+    pub details: Option<SummaryDetails>,
+    pub summary: Summary,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SummaryDetails {
+    pub precipitation_amount: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Body;
+
+    #[test]
+    fn parses_compact_body() {
+        let raw = r#"{
+            "type": "Feature",
+            "geometry": { "type": "Point", "coordinates": [14.4207, 50.088, 210] },
+            "properties": {
+                "meta": {
+                    "updated_at": "2023-03-21T09:00:00Z",
+                    "units": {
+                        "air_pressure_at_sea_level": "hPa",
+                        "air_temperature": "celsius",
+                        "cloud_area_fraction": "%",
+                        "precipitation_amount": "mm",
+                        "relative_humidity": "%",
+                        "wind_from_direction": "degrees",
+                        "wind_speed": "m/s"
+                    }
+                },
+                "timeseries": [{
+                    "time": "2023-03-21T10:00:00Z",
+                    "data": {
+                        "instant": {
+                            "details": {
+                                "air_pressure_at_sea_level": 1021.3,
+                                "air_temperature": 8.1,
+                                "cloud_area_fraction": 99.2,
+                                "relative_humidity": 78.4,
+                                "wind_from_direction": 255.9,
+                                "wind_speed": 3.4
+                            }
+                        },
+                        "next_12_hours": { "summary": { "symbol_code": "cloudy" } },
+                        "next_1_hours": {
+                            "summary": { "symbol_code": "cloudy" },
+                            "details": { "precipitation_amount": 0.0 }
+                        },
+                        "next_6_hours": {
+                            "summary": { "symbol_code": "lightrain" },
+                            "details": { "precipitation_amount": 0.4 }
+                        }
+                    }
+                }]
+            }
+        }"#;
+
+        let body: Body = serde_json::from_str(raw).unwrap();
+        let data = &body.properties.timeseries[0].data;
+
+        assert_eq!(data.instant.details.air_temperature, Some(8.1));
+        assert_eq!(data.next_12_hours.as_ref().unwrap().details, None);
+        assert_eq!(
+            data.next_6_hours
+                .as_ref()
+                .unwrap()
+                .details
+                .as_ref()
+                .unwrap()
+                .precipitation_amount,
+            Some(0.4)
+        );
+    }
+}
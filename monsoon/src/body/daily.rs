@@ -0,0 +1,301 @@
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+
+use super::{
+    periods::{dominant_symbol, hours, Period},
+    Body, Resolution, Symbol, TimeSeries,
+};
+
+/// Summary of a single day in a time zone, similar to what Yr shows in its daily overview. All of
+/// the values are in the units listed in [Meta::units].
+///
+/// [Meta::units]: super::Meta::units
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct DailySummary {
+    pub date: NaiveDate,
+    /// The dominant symbol of the night (00 - 06), morning (06 - 12), afternoon (12 - 18) and
+    /// evening (18 - 24), in this order. `None` if no period of the forecast overlaps the quarter,
+    /// e.g. for the quarters which have already passed or lie past the end of the forecast.
+    pub symbols: [Option<Symbol>; 4],
+    pub air_temperature_max: Option<f64>,
+    pub air_temperature_min: Option<f64>,
+    /// Total of the day. Intervals extending into the next day count only proportionally.
+    pub precipitation_amount: Option<f64>,
+    pub wind_speed_max: Option<f64>,
+}
+
+impl Body<'_> {
+    /// Aggregates the forecast into days in the given time zone.
+    ///
+    /// Example:
+    ///
+    /// ```no_run
+    /// use chrono::FixedOffset;
+    /// use monsoon::Monsoon;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    /// let monsoon = Monsoon::new("test.com support@test.com")?;
+    /// let response = monsoon.get(50.0880, 14.4207).await?;
+    ///
+    /// let tz = FixedOffset::east_opt(3600).unwrap();
+    /// for day in response.body()?.daily(&tz) {
+    ///     println!("{}: {:?} / {:?}", day.date, day.air_temperature_max, day.air_temperature_min);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn daily<Tz: TimeZone>(&self, tz: &Tz) -> Vec<DailySummary> {
+        let timeseries = &self.properties.timeseries;
+        let periods: Vec<_> = self.periods(Resolution::OneHour).collect();
+
+        let mut dates: Vec<_> = timeseries
+            .iter()
+            .map(|entry| entry.time.with_timezone(tz).date_naive())
+            .collect();
+        dates.dedup();
+
+        dates
+            .into_iter()
+            .filter_map(|date| {
+                let start = local_to_utc(tz, date, 0)?;
+                let end = local_to_utc(tz, date.succ_opt()?, 0)?;
+
+                let mut symbols = [None, None, None, None];
+                for (idx, symbol) in symbols.iter_mut().enumerate() {
+                    let period_start = local_to_utc(tz, date, idx as u32 * 6)?;
+                    let period_end = if idx == 3 {
+                        end
+                    } else {
+                        local_to_utc(tz, date, (idx as u32 + 1) * 6)?
+                    };
+
+                    *symbol = covering_symbol(&periods, period_start, period_end);
+                }
+
+                let (air_temperature_min, air_temperature_max) =
+                    air_temperature(timeseries, start, end);
+
+                Some(DailySummary {
+                    date,
+                    symbols,
+                    air_temperature_max,
+                    air_temperature_min,
+                    precipitation_amount: precipitation_amount(&periods, start, end),
+                    wind_speed_max: timeseries
+                        .iter()
+                        .filter(|entry| entry.time >= start && entry.time < end)
+                        .filter_map(|entry| entry.data.instant.details.wind_speed)
+                        .reduce(f64::max),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Returns the fraction of the period which overlaps with the given range.
+fn overlap(period: &Period, start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+    let overlap = period.end.min(end) - period.start.max(start);
+    if overlap <= Duration::zero() {
+        return 0.0;
+    }
+
+    overlap.num_seconds() as f64 / period.duration().num_seconds() as f64
+}
+
+fn precipitation_amount(
+    periods: &[Period],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Option<f64> {
+    periods
+        .iter()
+        .filter_map(|period| {
+            let overlap = overlap(period, start, end);
+            if overlap == 0.0 {
+                return None;
+            }
+
+            let amount = period.details.as_ref()?.precipitation_amount?;
+            Some(amount * overlap)
+        })
+        .reduce(|total, amount| total + amount)
+}
+
+/// Returns the symbol covering the biggest part of the given range.
+fn covering_symbol(periods: &[Period], start: DateTime<Utc>, end: DateTime<Utc>) -> Option<Symbol> {
+    let weights = periods.iter().filter_map(|period| {
+        let overlap = overlap(period, start, end);
+        let covered = overlap * period.duration().num_seconds() as f64;
+
+        (overlap > 0.0).then_some((&period.summary.symbol_code, covered))
+    });
+
+    dominant_symbol(weights).cloned()
+}
+
+/// Returns the min and max temperature. Uses the 6 hour extremes for intervals which fit into the
+/// given range, falling back to the instant values.
+fn air_temperature(
+    timeseries: &[TimeSeries],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> (Option<f64>, Option<f64>) {
+    let mut min: Option<f64> = None;
+    let mut max: Option<f64> = None;
+
+    for entry in timeseries {
+        if entry.time < start || entry.time >= end {
+            continue;
+        }
+
+        let mut values = vec![entry.data.instant.details.air_temperature];
+
+        if entry.time + hours(6) <= end {
+            if let Some(details) = entry
+                .data
+                .next_6_hours
+                .as_ref()
+                .and_then(|next| next.details.as_ref())
+            {
+                values.push(details.air_temperature_min);
+                values.push(details.air_temperature_max);
+            }
+        }
+
+        for value in values.into_iter().flatten() {
+            min = Some(min.map_or(value, |min| min.min(value)));
+            max = Some(max.map_or(value, |max| max.max(value)));
+        }
+    }
+
+    (min, max)
+}
+
+/// Converts the local time at the start of the given hour into UTC. Times skipped because of
+/// a DST change are shifted by an hour.
+fn local_to_utc<Tz: TimeZone>(tz: &Tz, date: NaiveDate, hour: u32) -> Option<DateTime<Utc>> {
+    let local = date.and_hms_opt(hour, 0, 0)?;
+
+    tz.from_local_datetime(&local)
+        .earliest()
+        .or_else(|| tz.from_local_datetime(&(local + hours(1))).earliest())
+        .map(|time| time.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{FixedOffset, NaiveDate};
+
+    use crate::body::{Body, Condition, Symbol};
+
+    fn entry(time: &str, temperature: f64, next: &str) -> String {
+        format!(
+            r#"{{
+                "time": "{}",
+                "data": {{
+                    "instant": {{ "details": {{ "air_temperature": {}, "wind_speed": {} }} }},
+                    {}
+                }}
+            }}"#,
+            time,
+            temperature,
+            temperature / 2.0,
+            next
+        )
+    }
+
+    fn next(hours: u32, symbol: &str, precipitation: f64) -> String {
+        format!(
+            r#""next_{}_hours": {{
+                "summary": {{ "symbol_code": "{}" }},
+                "details": {{ "precipitation_amount": {} }}
+            }}"#,
+            hours, symbol, precipitation
+        )
+    }
+
+    fn body(timeseries: &[String]) -> String {
+        format!(
+            r#"{{
+                "type": "Feature",
+                "geometry": {{ "type": "Point", "coordinates": [14.4207, 50.088, 210] }},
+                "properties": {{
+                    "meta": {{ "updated_at": "2023-03-21T09:00:00Z", "units": {{}} }},
+                    "timeseries": [{}]
+                }}
+            }}"#,
+            timeseries.join(",")
+        )
+    }
+
+    #[test]
+    fn aggregates_days() {
+        let mut timeseries: Vec<_> = (18..24)
+            .map(|hour| {
+                entry(
+                    &format!("2023-03-21T{:02}:00:00Z", hour),
+                    hour as f64 - 10.0,
+                    &[next(1, "rain", 1.0), next(6, "cloudy", 6.0)].join(","),
+                )
+            })
+            .collect();
+
+        // Switches to the 6 hour intervals
+        timeseries.push(entry("2023-03-22T00:00:00Z", 4.0, &next(6, "fog", 3.0)));
+        timeseries.push(entry(
+            "2023-03-22T06:00:00Z",
+            2.0,
+            &next(6, "fair_day", 0.0),
+        ));
+
+        let raw = body(&timeseries);
+        let body: Body = serde_json::from_str(&raw).unwrap();
+
+        let days = body.daily(&FixedOffset::east_opt(0).unwrap());
+        assert_eq!(days.len(), 2);
+
+        let day = &days[0];
+        assert_eq!(day.date, NaiveDate::from_ymd_opt(2023, 3, 21).unwrap());
+        assert_eq!(
+            day.symbols,
+            [None, None, None, Some(Symbol::new(Condition::Rain, None))]
+        );
+        assert_eq!(day.precipitation_amount, Some(6.0));
+        assert_eq!(day.air_temperature_min, Some(8.0));
+        assert_eq!(day.air_temperature_max, Some(13.0));
+        assert_eq!(day.wind_speed_max, Some(6.5));
+
+        let day = &days[1];
+        assert_eq!(day.precipitation_amount, Some(3.0));
+        assert_eq!(
+            day.symbols[..2],
+            [
+                Some(Symbol::new(Condition::Fog, None)),
+                Some("fair_day".parse().unwrap())
+            ]
+        );
+        assert_eq!(day.symbols[2], None);
+    }
+
+    #[test]
+    fn splits_intervals_across_days() {
+        let timeseries = [
+            entry("2023-03-21T18:00:00Z", 4.0, &next(6, "rain", 6.0)),
+            entry("2023-03-22T00:00:00Z", 4.0, &next(6, "snow", 6.0)),
+        ];
+
+        let raw = body(&timeseries);
+        let body: Body = serde_json::from_str(&raw).unwrap();
+
+        // 18:00 - 00:00 UTC is 20:00 - 02:00 in UTC+2
+        let days = body.daily(&FixedOffset::east_opt(2 * 3600).unwrap());
+        assert_eq!(days.len(), 2);
+
+        assert_eq!(days[0].precipitation_amount, Some(4.0));
+        assert_eq!(days[1].precipitation_amount, Some(8.0));
+
+        // Rain covers 2 hours of the night, snow the remaining 4
+        assert_eq!(days[1].symbols[0], Some(Symbol::new(Condition::Snow, None)));
+    }
+}
@@ -0,0 +1,194 @@
+//! Quantities derived from the instant values using the usual empirical formulas.
+use super::{
+    Density, DensityUnit, InstantDetails, Length, LengthUnit, SpeedUnit, Temperature,
+    TemperatureUnit, Unit, Units,
+};
+
+impl InstantDetails {
+    /// Returns the apparent ("feels like") temperature as defined by the [Australian Bureau of
+    /// Meteorology]. It takes the humidity and wind into account but not the solar radiation. The
+    /// result is in the unit of the air temperature.
+    ///
+    /// [Australian Bureau of Meteorology]: http://www.bom.gov.au/info/thermal_stress/
+    pub fn apparent_temperature(&self, units: &Units) -> Option<Temperature> {
+        let temperature = self.air_temperature_with_unit(units)?;
+        let humidity = self.relative_humidity_percent(units)?;
+        let wind_speed = self.wind_speed_with_unit(units)?.meters_per_second();
+
+        let celsius = temperature.celsius();
+        let vapour_pressure =
+            humidity / 100.0 * 6.105 * (17.27 * celsius / (237.7 + celsius)).exp();
+        let apparent = celsius + 0.33 * vapour_pressure - 0.70 * wind_speed - 4.00;
+
+        Some(Temperature::new(apparent, TemperatureUnit::Celsius).to(temperature.unit))
+    }
+
+    /// Returns the wind chill as defined by [Environment Canada]. It's only defined for
+    /// temperatures at or below 10 °C and wind speeds above 4.8 km/h, `None` is returned
+    /// otherwise. The result is in the unit of the air temperature.
+    ///
+    /// [Environment Canada]: https://www.canada.ca/en/environment-climate-change/services/weather-health/wind-chill-cold-weather/wind-chill-index.html
+    pub fn wind_chill(&self, units: &Units) -> Option<Temperature> {
+        let temperature = self.air_temperature_with_unit(units)?;
+        let wind_speed = self
+            .wind_speed_with_unit(units)?
+            .to(SpeedUnit::KilometersPerHour)
+            .value;
+
+        let celsius = temperature.celsius();
+        if celsius > 10.0 || wind_speed <= 4.8 {
+            return None;
+        }
+
+        let factor = wind_speed.powf(0.16);
+        let wind_chill = 13.12 + 0.6215 * celsius - 11.37 * factor + 0.3965 * celsius * factor;
+
+        Some(Temperature::new(wind_chill, TemperatureUnit::Celsius).to(temperature.unit))
+    }
+
+    /// Returns the heat index computed using the [algorithm of the US National Weather Service]
+    /// (the Rothfusz regression with adjustments). The result is in the unit of the air
+    /// temperature.
+    ///
+    /// [algorithm of the US National Weather Service]: https://www.wpc.ncep.noaa.gov/html/heatindex_equation.shtml
+    pub fn heat_index(&self, units: &Units) -> Option<Temperature> {
+        let temperature = self.air_temperature_with_unit(units)?;
+        let rh = self.relative_humidity_percent(units)?;
+
+        let t = temperature.fahrenheit();
+        let simple = 0.5 * (t + 61.0 + (t - 68.0) * 1.2 + rh * 0.094);
+
+        let heat_index = if (simple + t) / 2.0 < 80.0 {
+            simple
+        } else {
+            let mut heat_index = -42.379 + 2.049_015_23 * t + 10.143_331_27 * rh
+                - 0.224_755_41 * t * rh
+                - 0.006_837_83 * t * t
+                - 0.054_817_17 * rh * rh
+                + 0.001_228_74 * t * t * rh
+                + 0.000_852_82 * t * rh * rh
+                - 0.000_001_99 * t * t * rh * rh;
+
+            if rh < 13.0 && (80.0..=112.0).contains(&t) {
+                heat_index -= (13.0 - rh) / 4.0 * ((17.0 - (t - 95.0).abs()) / 17.0).sqrt();
+            } else if rh > 85.0 && (80.0..=87.0).contains(&t) {
+                heat_index += (rh - 85.0) / 10.0 * ((87.0 - t) / 5.0);
+            }
+
+            heat_index
+        };
+
+        Some(Temperature::new(heat_index, TemperatureUnit::Fahrenheit).to(temperature.unit))
+    }
+
+    /// Returns the mass of water vapour in a cubic meter of air.
+    pub fn absolute_humidity(&self, units: &Units) -> Option<Density> {
+        let celsius = self.air_temperature_with_unit(units)?.celsius();
+        let humidity = self.relative_humidity_percent(units)?;
+
+        let saturation_pressure = 6.112 * (17.67 * celsius / (celsius + 243.5)).exp();
+        let absolute = saturation_pressure * humidity * 2.1674 / (273.15 + celsius);
+
+        Some(Density::new(absolute, DensityUnit::GramsPerCubicMeter))
+    }
+
+    /// Returns the estimated height of the cloud base above the ground, computed from the spread
+    /// between the air temperature and the dew point (roughly 125 m per °C).
+    pub fn cloud_base(&self, units: &Units) -> Option<Length> {
+        let temperature = self.air_temperature_with_unit(units)?.celsius();
+        let dew_point = self.dew_point_temperature_with_unit(units)?.celsius();
+
+        let height = (temperature - dew_point).max(0.0) * 125.0;
+        Some(Length::new(height, LengthUnit::Meters))
+    }
+
+    fn relative_humidity_percent(&self, units: &Units) -> Option<f64> {
+        match units.relative_humidity {
+            Some(Unit::Percent) => self.relative_humidity,
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::body::{InstantDetails, Meta, TemperatureUnit};
+
+    const META: &str = r#"{
+        "updated_at": "2023-03-21T09:00:00Z",
+        "units": {
+            "air_temperature": "celsius",
+            "dew_point_temperature": "celsius",
+            "relative_humidity": "%",
+            "wind_speed": "m/s"
+        }
+    }"#;
+
+    fn details(air_temperature: f64, relative_humidity: f64, wind_speed: f64) -> InstantDetails {
+        serde_json::from_str(&format!(
+            r#"{{
+                "air_temperature": {},
+                "dew_point_temperature": 12.0,
+                "relative_humidity": {},
+                "wind_speed": {}
+            }}"#,
+            air_temperature, relative_humidity, wind_speed
+        ))
+        .unwrap()
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 0.01, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn computes_apparent_temperature() {
+        let meta: Meta = serde_json::from_str(META).unwrap();
+
+        let temperature = details(25.0, 50.0, 2.0)
+            .apparent_temperature(&meta.units)
+            .unwrap();
+        assert_eq!(temperature.unit, TemperatureUnit::Celsius);
+        assert_close(temperature.value, 24.81);
+    }
+
+    #[test]
+    fn computes_wind_chill() {
+        let meta: Meta = serde_json::from_str(META).unwrap();
+
+        let temperature = details(-10.0, 50.0, 20.0 / 3.6)
+            .wind_chill(&meta.units)
+            .unwrap();
+        assert_close(temperature.value, -17.86);
+
+        assert_eq!(details(15.0, 50.0, 10.0).wind_chill(&meta.units), None);
+        assert_eq!(details(-10.0, 50.0, 1.0).wind_chill(&meta.units), None);
+    }
+
+    #[test]
+    fn computes_heat_index() {
+        let meta: Meta = serde_json::from_str(META).unwrap();
+
+        let temperature = details(32.2222, 60.0, 0.0).heat_index(&meta.units).unwrap();
+        assert_close(temperature.fahrenheit(), 99.68);
+
+        // Below 80 °F, the simple formula is used
+        let temperature = details(20.0, 50.0, 0.0).heat_index(&meta.units).unwrap();
+        assert_close(temperature.value, 19.36);
+    }
+
+    #[test]
+    fn computes_humidity_and_cloud_base() {
+        let meta: Meta = serde_json::from_str(META).unwrap();
+        let details = details(20.0, 50.0, 0.0);
+
+        assert_close(
+            details
+                .absolute_humidity(&meta.units)
+                .unwrap()
+                .grams_per_cubic_meter(),
+            8.64,
+        );
+        assert_close(details.cloud_base(&meta.units).unwrap().meters(), 1000.0);
+    }
+}
@@ -0,0 +1,152 @@
+use chrono::{DateTime, Utc};
+
+use super::{Body, InstantDetails};
+
+impl Body<'_> {
+    /// Returns the instant values at the given time, linearly interpolated between the nearest
+    /// entries of the forecast. The wind direction takes the shorter way around the circle.
+    /// Returns `None` if the time is outside of the forecast. Each value is known only if it's
+    /// present in both of the entries.
+    ///
+    /// Example:
+    ///
+    /// ```no_run
+    /// use chrono::{Duration, Utc};
+    /// use monsoon::Monsoon;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    /// let monsoon = Monsoon::new("test.com support@test.com")?;
+    /// let response = monsoon.get(50.0880, 14.4207).await?;
+    ///
+    /// let at = Utc::now() + Duration::try_minutes(80).unwrap();
+    /// if let Some(details) = response.body()?.interpolate(at) {
+    ///     println!("{:?}", details.air_temperature);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn interpolate(&self, at: DateTime<Utc>) -> Option<InstantDetails> {
+        let timeseries = &self.properties.timeseries;
+
+        let idx = timeseries.partition_point(|entry| entry.time < at);
+        let next = timeseries.get(idx)?;
+        if next.time == at {
+            return Some(next.data.instant.details.clone());
+        }
+
+        let prev = timeseries.get(idx.checked_sub(1)?)?;
+        let fraction = (at - prev.time).num_milliseconds() as f64
+            / (next.time - prev.time).num_milliseconds() as f64;
+
+        let a = &prev.data.instant.details;
+        let b = &next.data.instant.details;
+        let lerp = |a: Option<f64>, b: Option<f64>| Some(a? + (b? - a?) * fraction);
+
+        Some(InstantDetails {
+            air_pressure_at_sea_level: lerp(
+                a.air_pressure_at_sea_level,
+                b.air_pressure_at_sea_level,
+            ),
+            air_temperature: lerp(a.air_temperature, b.air_temperature),
+            cloud_area_fraction: lerp(a.cloud_area_fraction, b.cloud_area_fraction),
+            cloud_area_fraction_high: lerp(a.cloud_area_fraction_high, b.cloud_area_fraction_high),
+            cloud_area_fraction_low: lerp(a.cloud_area_fraction_low, b.cloud_area_fraction_low),
+            cloud_area_fraction_medium: lerp(
+                a.cloud_area_fraction_medium,
+                b.cloud_area_fraction_medium,
+            ),
+            dew_point_temperature: lerp(a.dew_point_temperature, b.dew_point_temperature),
+            fog_area_fraction: lerp(a.fog_area_fraction, b.fog_area_fraction),
+            relative_humidity: lerp(a.relative_humidity, b.relative_humidity),
+            ultraviolet_index_clear_sky: lerp(
+                a.ultraviolet_index_clear_sky,
+                b.ultraviolet_index_clear_sky,
+            ),
+            wind_from_direction: lerp_direction(
+                a.wind_from_direction,
+                b.wind_from_direction,
+                fraction,
+            ),
+            wind_speed: lerp(a.wind_speed, b.wind_speed),
+        })
+    }
+}
+
+/// Interpolates between two directions in degrees along the shorter arc.
+fn lerp_direction(a: Option<f64>, b: Option<f64>, fraction: f64) -> Option<f64> {
+    let (a, b) = (a?, b?);
+    let delta = (b - a + 540.0).rem_euclid(360.0) - 180.0;
+
+    Some((a + delta * fraction).rem_euclid(360.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::body::Body;
+
+    const BODY: &str = r#"{
+        "type": "Feature",
+        "geometry": { "type": "Point", "coordinates": [14.4207, 50.088, 210] },
+        "properties": {
+            "meta": { "updated_at": "2023-03-21T09:00:00Z", "units": {} },
+            "timeseries": [
+                {
+                    "time": "2023-03-21T10:00:00Z",
+                    "data": { "instant": { "details": {
+                        "air_temperature": 10.0, "wind_from_direction": 350.0, "wind_speed": 4.0
+                    } } }
+                },
+                {
+                    "time": "2023-03-21T11:00:00Z",
+                    "data": { "instant": { "details": {
+                        "air_temperature": 13.0, "wind_from_direction": 20.0
+                    } } }
+                }
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn interpolates_values() {
+        let body: Body = serde_json::from_str(BODY).unwrap();
+
+        let details = body
+            .interpolate(Utc.with_ymd_and_hms(2023, 3, 21, 10, 20, 0).unwrap())
+            .unwrap();
+        assert_eq!(details.air_temperature, Some(11.0));
+        assert_eq!(details.wind_speed, None);
+
+        let direction = details.wind_from_direction.unwrap();
+        assert!((direction - 0.0).abs() < 1e-9 || (direction - 360.0).abs() < 1e-9);
+
+        let details = body
+            .interpolate(Utc.with_ymd_and_hms(2023, 3, 21, 10, 40, 0).unwrap())
+            .unwrap();
+        assert!((details.wind_from_direction.unwrap() - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn returns_exact_values() {
+        let body: Body = serde_json::from_str(BODY).unwrap();
+
+        let details = body
+            .interpolate(Utc.with_ymd_and_hms(2023, 3, 21, 10, 0, 0).unwrap())
+            .unwrap();
+        assert_eq!(details.wind_speed, Some(4.0));
+    }
+
+    #[test]
+    fn returns_none_outside_of_forecast() {
+        let body: Body = serde_json::from_str(BODY).unwrap();
+
+        assert!(body
+            .interpolate(Utc.with_ymd_and_hms(2023, 3, 21, 9, 59, 0).unwrap())
+            .is_none());
+        assert!(body
+            .interpolate(Utc.with_ymd_and_hms(2023, 3, 21, 11, 1, 0).unwrap())
+            .is_none());
+    }
+}
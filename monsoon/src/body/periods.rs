@@ -0,0 +1,410 @@
+use chrono::{DateTime, Duration, Timelike, Utc};
+
+use super::{Body, NextHours, Summary, SummaryDetails, Symbol, TimeSeries};
+
+/// Requested length of the periods returned by [Body::periods].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Resolution {
+    OneHour,
+    ThreeHours,
+    SixHours,
+}
+
+impl Resolution {
+    fn hours(self) -> u32 {
+        match self {
+            Resolution::OneHour => 1,
+            Resolution::ThreeHours => 3,
+            Resolution::SixHours => 6,
+        }
+    }
+}
+
+/// Forecast interval the values of a [Period] came from, i.e. one of `next_1_hours`,
+/// `next_6_hours` and `next_12_hours`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interval {
+    OneHour,
+    SixHours,
+    TwelveHours,
+}
+
+impl Interval {
+    fn duration(self) -> Duration {
+        match self {
+            Interval::OneHour => hours(1),
+            Interval::SixHours => hours(6),
+            Interval::TwelveHours => hours(12),
+        }
+    }
+}
+
+/// `Duration::hours` is deprecated in favor of `Duration::try_hours` since chrono 0.4.35.
+pub(super) fn hours(hours: i64) -> Duration {
+    Duration::try_hours(hours).expect("hours within range")
+}
+
+/// Non-overlapping part of the forecast returned by [Body::periods].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Period {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// The interval the values came from. Periods combined from several hourly intervals have
+    /// [Interval::OneHour].
+    pub source: Interval,
+    pub details: Option<SummaryDetails>,
+    pub summary: Summary,
+}
+
+impl Period {
+    fn new(time: DateTime<Utc>, source: Interval, next_hours: &NextHours) -> Self {
+        Self {
+            start: time,
+            end: time + source.duration(),
+            source,
+            details: next_hours.details.clone(),
+            summary: next_hours.summary.clone(),
+        }
+    }
+
+    /// Returns the length of the period.
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+}
+
+impl Body<'_> {
+    /// Walks the forecast and returns non-overlapping periods of the given resolution. The hourly
+    /// data is available only for the first couple of days, after that the periods are longer
+    /// than requested. Shorter periods are also used at the beginning until the start is aligned
+    /// with the resolution (in UTC). Check [Period::source] to see where the values came from.
+    ///
+    /// Example:
+    ///
+    /// ```no_run
+    /// use monsoon::{body::Resolution, Monsoon};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    /// let monsoon = Monsoon::new("test.com support@test.com")?;
+    /// let response = monsoon.get(50.0880, 14.4207).await?;
+    ///
+    /// for period in response.body()?.periods(Resolution::ThreeHours) {
+    ///     let precipitation = period.details.and_then(|details| details.precipitation_amount);
+    ///     println!("{} - {}: {:?}", period.start, period.end, precipitation);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn periods(&self, resolution: Resolution) -> Periods<'_> {
+        Periods {
+            timeseries: &self.properties.timeseries,
+            resolution,
+            end: None,
+        }
+    }
+}
+
+/// Iterator returned by [Body::periods].
+#[derive(Debug, Clone)]
+pub struct Periods<'a> {
+    timeseries: &'a [TimeSeries],
+    resolution: Resolution,
+    end: Option<DateTime<Utc>>,
+}
+
+impl Iterator for Periods<'_> {
+    type Item = Period;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (entry, rest) = self.timeseries.split_first()?;
+            self.timeseries = rest;
+
+            if matches!(self.end, Some(end) if entry.time < end) {
+                continue;
+            }
+
+            if let Some(period) = next_period(entry, rest, self.resolution) {
+                self.end = Some(period.end);
+                return Some(period);
+            }
+        }
+    }
+}
+
+fn next_period(entry: &TimeSeries, rest: &[TimeSeries], resolution: Resolution) -> Option<Period> {
+    let time = entry.time;
+    let data = &entry.data;
+
+    // u32::is_multiple_of requires Rust 1.87
+    #[allow(unknown_lints, clippy::manual_is_multiple_of)]
+    let aligned = time.minute() == 0 && time.hour() % resolution.hours() == 0;
+    if aligned {
+        let period = match resolution {
+            Resolution::OneHour => data
+                .next_1_hours
+                .as_ref()
+                .map(|next| Period::new(time, Interval::OneHour, next)),
+            Resolution::ThreeHours => combine_hours(entry, rest, 3),
+            Resolution::SixHours => data
+                .next_6_hours
+                .as_ref()
+                .map(|next| Period::new(time, Interval::SixHours, next)),
+        };
+
+        if period.is_some() {
+            return period;
+        }
+    }
+
+    if let Some(next) = &data.next_1_hours {
+        Some(Period::new(time, Interval::OneHour, next))
+    } else if let Some(next) = &data.next_6_hours {
+        Some(Period::new(time, Interval::SixHours, next))
+    } else {
+        data.next_12_hours
+            .as_ref()
+            .map(|next| Period::new(time, Interval::TwelveHours, next))
+    }
+}
+
+/// Combines consecutive hourly intervals into a single period. Returns `None` if any of them is
+/// missing.
+fn combine_hours(entry: &TimeSeries, rest: &[TimeSeries], count: usize) -> Option<Period> {
+    let blocks: Vec<_> = std::iter::once(entry)
+        .chain(rest.iter())
+        .take(count)
+        .enumerate()
+        .map_while(|(idx, next)| {
+            (next.time == entry.time + hours(idx as i64))
+                .then_some(next.data.next_1_hours.as_ref())
+                .flatten()
+        })
+        .collect();
+
+    if blocks.len() != count {
+        return None;
+    }
+
+    let symbol = dominant_symbol(blocks.iter().map(|next| (&next.summary.symbol_code, 1.0)))?;
+
+    let details: Vec<_> = blocks.iter().map(|next| next.details.as_ref()).collect();
+    let details = details
+        .iter()
+        .any(Option::is_some)
+        .then(|| combine_details(&details));
+
+    Some(Period {
+        start: entry.time,
+        end: entry.time + hours(count as i64),
+        source: Interval::OneHour,
+        details,
+        summary: Summary {
+            symbol_code: symbol.clone(),
+        },
+    })
+}
+
+/// Combines the details of consecutive intervals. The amounts are summed up (and known only if
+/// all of the intervals have them), the rest takes the extreme value. Note that the maximum of
+/// the probabilities is only an approximation of the probability for the whole period.
+fn combine_details(details: &[Option<&SummaryDetails>]) -> SummaryDetails {
+    let values = |value: fn(&SummaryDetails) -> Option<f64>| {
+        details.iter().map(move |details| details.and_then(value))
+    };
+
+    let sum = |value| values(value).sum::<Option<f64>>();
+    let max = |value| values(value).flatten().reduce(f64::max);
+    let min = |value| values(value).flatten().reduce(f64::min);
+
+    SummaryDetails {
+        air_temperature_max: max(|d| d.air_temperature_max),
+        air_temperature_min: min(|d| d.air_temperature_min),
+        precipitation_amount: sum(|d| d.precipitation_amount),
+        precipitation_amount_max: sum(|d| d.precipitation_amount_max),
+        precipitation_amount_min: sum(|d| d.precipitation_amount_min),
+        probability_of_precipitation: max(|d| d.probability_of_precipitation),
+        probability_of_thunder: max(|d| d.probability_of_thunder),
+        ultraviolet_index_clear_sky_max: max(|d| d.ultraviolet_index_clear_sky_max),
+    }
+}
+
+/// Returns the symbol with the biggest total weight. Ties are won by the earlier symbol.
+pub(super) fn dominant_symbol<'a>(
+    weights: impl IntoIterator<Item = (&'a Symbol, f64)>,
+) -> Option<&'a Symbol> {
+    let mut totals: Vec<(&Symbol, f64)> = Vec::new();
+
+    for (symbol, weight) in weights {
+        match totals.iter_mut().find(|(other, _)| *other == symbol) {
+            Some((_, total)) => *total += weight,
+            None => totals.push((symbol, weight)),
+        }
+    }
+
+    totals
+        .into_iter()
+        .rev()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(symbol, _)| symbol)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+
+    use super::{Interval, Resolution};
+    use crate::body::{Body, Condition};
+
+    fn entry(time: &str, next: &[(u32, &str, f64)]) -> String {
+        let next: Vec<_> = next
+            .iter()
+            .map(|(hours, symbol, precipitation)| {
+                format!(
+                    r#""next_{}_hours": {{
+                        "summary": {{ "symbol_code": "{}" }},
+                        "details": {{ "precipitation_amount": {} }}
+                    }}"#,
+                    hours, symbol, precipitation
+                )
+            })
+            .collect();
+
+        format!(
+            r#"{{
+                "time": "{}",
+                "data": {{ "instant": {{ "details": {{}} }}, {} }}
+            }}"#,
+            time,
+            next.join(",")
+        )
+    }
+
+    fn body() -> String {
+        let mut timeseries: Vec<_> = (10..18)
+            .map(|hour| {
+                let symbol = if hour < 16 { "rain" } else { "cloudy" };
+                entry(
+                    &format!("2023-03-21T{:02}:00:00Z", hour),
+                    &[(1, symbol, 1.0), (6, "rain", 6.0)],
+                )
+            })
+            .collect();
+
+        timeseries.push(entry(
+            "2023-03-21T18:00:00Z",
+            &[(6, "fog", 2.0), (12, "fog", 4.0)],
+        ));
+        timeseries.push(entry("2023-03-22T00:00:00Z", &[(12, "snow", 3.0)]));
+
+        feature(&timeseries)
+    }
+
+    fn feature(timeseries: &[String]) -> String {
+        format!(
+            r#"{{
+                "type": "Feature",
+                "geometry": {{ "type": "Point", "coordinates": [14.4207, 50.088, 210] }},
+                "properties": {{
+                    "meta": {{ "updated_at": "2023-03-21T09:00:00Z", "units": {{}} }},
+                    "timeseries": [{}]
+                }}
+            }}"#,
+            timeseries.join(",")
+        )
+    }
+
+    fn hours(body: &Body, resolution: Resolution) -> Vec<(u32, i64, Interval)> {
+        let start = Utc.with_ymd_and_hms(2023, 3, 21, 0, 0, 0).unwrap();
+
+        body.periods(resolution)
+            .map(|period| {
+                let hour = (period.start - start).num_hours() as u32;
+                (hour, period.duration().num_hours(), period.source)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn walks_hourly_periods() {
+        let raw = body();
+        let body: Body = serde_json::from_str(&raw).unwrap();
+
+        let periods = hours(&body, Resolution::OneHour);
+        assert_eq!(periods.len(), 10);
+        assert_eq!(periods[7], (17, 1, Interval::OneHour));
+        assert_eq!(periods[8], (18, 6, Interval::SixHours));
+        assert_eq!(periods[9], (24, 12, Interval::TwelveHours));
+
+        let total: f64 = body
+            .periods(Resolution::OneHour)
+            .filter_map(|period| period.details?.precipitation_amount)
+            .sum();
+        assert_eq!(total, 13.0);
+    }
+
+    #[test]
+    fn combines_hourly_periods() {
+        let raw = body();
+        let body: Body = serde_json::from_str(&raw).unwrap();
+
+        assert_eq!(
+            hours(&body, Resolution::ThreeHours),
+            [
+                (10, 1, Interval::OneHour),
+                (11, 1, Interval::OneHour),
+                (12, 3, Interval::OneHour),
+                (15, 3, Interval::OneHour),
+                (18, 6, Interval::SixHours),
+                (24, 12, Interval::TwelveHours),
+            ]
+        );
+
+        let period = body.periods(Resolution::ThreeHours).nth(3).unwrap();
+        assert_eq!(period.end - period.start, Duration::try_hours(3).unwrap());
+        assert_eq!(period.details.unwrap().precipitation_amount, Some(3.0));
+        assert_eq!(period.summary.symbol_code.condition, Condition::Cloudy);
+    }
+
+    #[test]
+    fn combines_amounts_only_if_all_hours_have_them() {
+        let timeseries = [
+            entry("2023-03-21T12:00:00Z", &[(1, "rain", 1.0)]),
+            r#"{
+                "time": "2023-03-21T13:00:00Z",
+                "data": {
+                    "instant": { "details": {} },
+                    "next_1_hours": { "summary": { "symbol_code": "rain" } }
+                }
+            }"#
+            .to_string(),
+            entry("2023-03-21T14:00:00Z", &[(1, "rain", 1.0)]),
+        ];
+
+        let raw = feature(&timeseries);
+        let body: Body = serde_json::from_str(&raw).unwrap();
+
+        let period = body.periods(Resolution::ThreeHours).next().unwrap();
+        assert_eq!(period.end - period.start, Duration::try_hours(3).unwrap());
+        assert_eq!(period.details.unwrap().precipitation_amount, None);
+    }
+
+    #[test]
+    fn prefers_six_hour_periods() {
+        let raw = body();
+        let body: Body = serde_json::from_str(&raw).unwrap();
+
+        assert_eq!(
+            hours(&body, Resolution::SixHours),
+            [
+                (10, 1, Interval::OneHour),
+                (11, 1, Interval::OneHour),
+                (12, 6, Interval::SixHours),
+                (18, 6, Interval::SixHours),
+                (24, 12, Interval::TwelveHours),
+            ]
+        );
+    }
+}
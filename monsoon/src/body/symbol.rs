@@ -0,0 +1,352 @@
+//! Weather symbols as returned in [Summary::symbol_code]. See the [`documentation`] for the list
+//! of all symbols.
+//!
+//! [Summary::symbol_code]: super::Summary::symbol_code
+//! [`documentation`]: https://api.met.no/weatherapi/weathericon/2.0/documentation
+use std::{borrow::Cow, convert::Infallible, fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer};
+
+/// A weather symbol such as "partlycloudy_day". It consists of the weather condition and,
+/// for some conditions, the variant signifying the position of the sun.
+///
+/// Parsing never fails. Codes which aren't known (yet) are preserved as [Condition::Unknown].
+///
+/// Example:
+///
+/// ```
+/// use monsoon::body::{Condition, Symbol, Variant};
+///
+/// let symbol: Symbol = "partlycloudy_day".parse().unwrap();
+/// assert_eq!(symbol.condition, Condition::PartlyCloudy);
+/// assert_eq!(symbol.variant, Some(Variant::Day));
+/// assert_eq!(symbol.description(), "Partly cloudy");
+/// assert_eq!(symbol.to_string(), "partlycloudy_day");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Symbol {
+    pub condition: Condition,
+    pub variant: Option<Variant>,
+}
+
+impl Symbol {
+    pub fn new(condition: Condition, variant: impl Into<Option<Variant>>) -> Self {
+        Self {
+            condition,
+            variant: variant.into(),
+        }
+    }
+
+    /// Returns a human-readable description of the condition, e.g. "Light rain showers".
+    pub fn description(&self) -> &'static str {
+        self.condition.description()
+    }
+}
+
+impl FromStr for Symbol {
+    type Err = Infallible;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        if let Some((condition, variant)) = code.rsplit_once('_') {
+            if let Ok(variant) = variant.parse() {
+                return Ok(Self::new(condition.parse()?, Some(variant)));
+            }
+        }
+
+        Ok(Self::new(code.parse()?, None))
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.variant {
+            Some(variant) => write!(f, "{}_{}", self.condition, variant),
+            None => write!(f, "{}", self.condition),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Symbol {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = Cow::<str>::deserialize(deserializer)?;
+        Ok(code.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+
+/// The weather condition of a [Symbol].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Condition {
+    ClearSky,
+    Fair,
+    PartlyCloudy,
+    Cloudy,
+    RainShowers,
+    RainShowersAndThunder,
+    SleetShowers,
+    SnowShowers,
+    Rain,
+    HeavyRain,
+    HeavyRainAndThunder,
+    Sleet,
+    Snow,
+    SnowAndThunder,
+    Fog,
+    SleetShowersAndThunder,
+    SnowShowersAndThunder,
+    RainAndThunder,
+    SleetAndThunder,
+    LightRainShowersAndThunder,
+    HeavyRainShowersAndThunder,
+    LightSleetShowersAndThunder,
+    HeavySleetShowersAndThunder,
+    LightSnowShowersAndThunder,
+    HeavySnowShowersAndThunder,
+    LightRainAndThunder,
+    LightSleetAndThunder,
+    HeavySleetAndThunder,
+    LightSnowAndThunder,
+    HeavySnowAndThunder,
+    LightRainShowers,
+    HeavyRainShowers,
+    LightSleetShowers,
+    HeavySleetShowers,
+    LightSnowShowers,
+    HeavySnowShowers,
+    LightRain,
+    LightSleet,
+    HeavySleet,
+    LightSnow,
+    HeavySnow,
+    /// A condition not known to this version of the library. Holds the original code without
+    /// the variant.
+    Unknown(Box<str>),
+}
+
+impl Condition {
+    /// Returns the code used by the API, e.g. "lightrainshowers".
+    pub fn code(&self) -> &str {
+        match self {
+            Condition::ClearSky => "clearsky",
+            Condition::Fair => "fair",
+            Condition::PartlyCloudy => "partlycloudy",
+            Condition::Cloudy => "cloudy",
+            Condition::RainShowers => "rainshowers",
+            Condition::RainShowersAndThunder => "rainshowersandthunder",
+            Condition::SleetShowers => "sleetshowers",
+            Condition::SnowShowers => "snowshowers",
+            Condition::Rain => "rain",
+            Condition::HeavyRain => "heavyrain",
+            Condition::HeavyRainAndThunder => "heavyrainandthunder",
+            Condition::Sleet => "sleet",
+            Condition::Snow => "snow",
+            Condition::SnowAndThunder => "snowandthunder",
+            Condition::Fog => "fog",
+            Condition::SleetShowersAndThunder => "sleetshowersandthunder",
+            Condition::SnowShowersAndThunder => "snowshowersandthunder",
+            Condition::RainAndThunder => "rainandthunder",
+            Condition::SleetAndThunder => "sleetandthunder",
+            Condition::LightRainShowersAndThunder => "lightrainshowersandthunder",
+            Condition::HeavyRainShowersAndThunder => "heavyrainshowersandthunder",
+            Condition::LightSleetShowersAndThunder => "lightssleetshowersandthunder",
+            Condition::HeavySleetShowersAndThunder => "heavysleetshowersandthunder",
+            Condition::LightSnowShowersAndThunder => "lightssnowshowersandthunder",
+            Condition::HeavySnowShowersAndThunder => "heavysnowshowersandthunder",
+            Condition::LightRainAndThunder => "lightrainandthunder",
+            Condition::LightSleetAndThunder => "lightsleetandthunder",
+            Condition::HeavySleetAndThunder => "heavysleetandthunder",
+            Condition::LightSnowAndThunder => "lightsnowandthunder",
+            Condition::HeavySnowAndThunder => "heavysnowandthunder",
+            Condition::LightRainShowers => "lightrainshowers",
+            Condition::HeavyRainShowers => "heavyrainshowers",
+            Condition::LightSleetShowers => "lightsleetshowers",
+            Condition::HeavySleetShowers => "heavysleetshowers",
+            Condition::LightSnowShowers => "lightsnowshowers",
+            Condition::HeavySnowShowers => "heavysnowshowers",
+            Condition::LightRain => "lightrain",
+            Condition::LightSleet => "lightsleet",
+            Condition::HeavySleet => "heavysleet",
+            Condition::LightSnow => "lightsnow",
+            Condition::HeavySnow => "heavysnow",
+            Condition::Unknown(code) => code,
+        }
+    }
+
+    /// Returns a human-readable description, e.g. "Light rain showers".
+    pub fn description(&self) -> &'static str {
+        match self {
+            Condition::ClearSky => "Clear sky",
+            Condition::Fair => "Fair",
+            Condition::PartlyCloudy => "Partly cloudy",
+            Condition::Cloudy => "Cloudy",
+            Condition::RainShowers => "Rain showers",
+            Condition::RainShowersAndThunder => "Rain showers and thunder",
+            Condition::SleetShowers => "Sleet showers",
+            Condition::SnowShowers => "Snow showers",
+            Condition::Rain => "Rain",
+            Condition::HeavyRain => "Heavy rain",
+            Condition::HeavyRainAndThunder => "Heavy rain and thunder",
+            Condition::Sleet => "Sleet",
+            Condition::Snow => "Snow",
+            Condition::SnowAndThunder => "Snow and thunder",
+            Condition::Fog => "Fog",
+            Condition::SleetShowersAndThunder => "Sleet showers and thunder",
+            Condition::SnowShowersAndThunder => "Snow showers and thunder",
+            Condition::RainAndThunder => "Rain and thunder",
+            Condition::SleetAndThunder => "Sleet and thunder",
+            Condition::LightRainShowersAndThunder => "Light rain showers and thunder",
+            Condition::HeavyRainShowersAndThunder => "Heavy rain showers and thunder",
+            Condition::LightSleetShowersAndThunder => "Light sleet showers and thunder",
+            Condition::HeavySleetShowersAndThunder => "Heavy sleet showers and thunder",
+            Condition::LightSnowShowersAndThunder => "Light snow showers and thunder",
+            Condition::HeavySnowShowersAndThunder => "Heavy snow showers and thunder",
+            Condition::LightRainAndThunder => "Light rain and thunder",
+            Condition::LightSleetAndThunder => "Light sleet and thunder",
+            Condition::HeavySleetAndThunder => "Heavy sleet and thunder",
+            Condition::LightSnowAndThunder => "Light snow and thunder",
+            Condition::HeavySnowAndThunder => "Heavy snow and thunder",
+            Condition::LightRainShowers => "Light rain showers",
+            Condition::HeavyRainShowers => "Heavy rain showers",
+            Condition::LightSleetShowers => "Light sleet showers",
+            Condition::HeavySleetShowers => "Heavy sleet showers",
+            Condition::LightSnowShowers => "Light snow showers",
+            Condition::HeavySnowShowers => "Heavy snow showers",
+            Condition::LightRain => "Light rain",
+            Condition::LightSleet => "Light sleet",
+            Condition::HeavySleet => "Heavy sleet",
+            Condition::LightSnow => "Light snow",
+            Condition::HeavySnow => "Heavy snow",
+            Condition::Unknown(_) => "Unknown",
+        }
+    }
+}
+
+impl FromStr for Condition {
+    type Err = Infallible;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Ok(match code {
+            "clearsky" => Condition::ClearSky,
+            "fair" => Condition::Fair,
+            "partlycloudy" => Condition::PartlyCloudy,
+            "cloudy" => Condition::Cloudy,
+            "rainshowers" => Condition::RainShowers,
+            "rainshowersandthunder" => Condition::RainShowersAndThunder,
+            "sleetshowers" => Condition::SleetShowers,
+            "snowshowers" => Condition::SnowShowers,
+            "rain" => Condition::Rain,
+            "heavyrain" => Condition::HeavyRain,
+            "heavyrainandthunder" => Condition::HeavyRainAndThunder,
+            "sleet" => Condition::Sleet,
+            "snow" => Condition::Snow,
+            "snowandthunder" => Condition::SnowAndThunder,
+            "fog" => Condition::Fog,
+            "sleetshowersandthunder" => Condition::SleetShowersAndThunder,
+            "snowshowersandthunder" => Condition::SnowShowersAndThunder,
+            "rainandthunder" => Condition::RainAndThunder,
+            "sleetandthunder" => Condition::SleetAndThunder,
+            "lightrainshowersandthunder" => Condition::LightRainShowersAndThunder,
+            "heavyrainshowersandthunder" => Condition::HeavyRainShowersAndThunder,
+            "lightssleetshowersandthunder" => Condition::LightSleetShowersAndThunder,
+            "heavysleetshowersandthunder" => Condition::HeavySleetShowersAndThunder,
+            "lightssnowshowersandthunder" => Condition::LightSnowShowersAndThunder,
+            "heavysnowshowersandthunder" => Condition::HeavySnowShowersAndThunder,
+            "lightrainandthunder" => Condition::LightRainAndThunder,
+            "lightsleetandthunder" => Condition::LightSleetAndThunder,
+            "heavysleetandthunder" => Condition::HeavySleetAndThunder,
+            "lightsnowandthunder" => Condition::LightSnowAndThunder,
+            "heavysnowandthunder" => Condition::HeavySnowAndThunder,
+            "lightrainshowers" => Condition::LightRainShowers,
+            "heavyrainshowers" => Condition::HeavyRainShowers,
+            "lightsleetshowers" => Condition::LightSleetShowers,
+            "heavysleetshowers" => Condition::HeavySleetShowers,
+            "lightsnowshowers" => Condition::LightSnowShowers,
+            "heavysnowshowers" => Condition::HeavySnowShowers,
+            "lightrain" => Condition::LightRain,
+            "lightsleet" => Condition::LightSleet,
+            "heavysleet" => Condition::HeavySleet,
+            "lightsnow" => Condition::LightSnow,
+            "heavysnow" => Condition::HeavySnow,
+            code => Condition::Unknown(code.into()),
+        })
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// The position of the sun the [Symbol] is meant for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Variant {
+    Day,
+    Night,
+    PolarTwilight,
+}
+
+impl Variant {
+    /// Returns the suffix used by the API, e.g. "polartwilight".
+    pub fn code(&self) -> &'static str {
+        match self {
+            Variant::Day => "day",
+            Variant::Night => "night",
+            Variant::PolarTwilight => "polartwilight",
+        }
+    }
+}
+
+impl FromStr for Variant {
+    type Err = ();
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        match code {
+            "day" => Ok(Variant::Day),
+            "night" => Ok(Variant::Night),
+            "polartwilight" => Ok(Variant::PolarTwilight),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Condition, Symbol, Variant};
+
+    #[test]
+    fn parses_symbols() {
+        let symbol: Symbol = "lightssnowshowersandthunder_polartwilight".parse().unwrap();
+        assert_eq!(symbol.condition, Condition::LightSnowShowersAndThunder);
+        assert_eq!(symbol.variant, Some(Variant::PolarTwilight));
+
+        let symbol: Symbol = "heavysleet".parse().unwrap();
+        assert_eq!(symbol, Symbol::new(Condition::HeavySleet, None));
+        assert_eq!(symbol.description(), "Heavy sleet");
+    }
+
+    #[test]
+    fn falls_back_to_unknown_condition() {
+        let symbol: Symbol = "meteorshower_night".parse().unwrap();
+        assert_eq!(symbol.condition, Condition::Unknown("meteorshower".into()));
+        assert_eq!(symbol.variant, Some(Variant::Night));
+        assert_eq!(symbol.description(), "Unknown");
+
+        let symbol: Symbol = "fog_evening".parse().unwrap();
+        assert_eq!(symbol.condition, Condition::Unknown("fog_evening".into()));
+        assert_eq!(symbol.variant, None);
+    }
+
+    #[test]
+    fn displays_original_code() {
+        for code in ["clearsky_day", "fair_night", "cloudy", "something_new"] {
+            assert_eq!(code.parse::<Symbol>().unwrap().to_string(), code);
+        }
+    }
+}
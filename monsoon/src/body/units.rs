@@ -0,0 +1,540 @@
+//! Units of the values in the body and conversions between them.
+use std::{borrow::Cow, convert::Infallible, fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer};
+
+/// Unit of a value as listed in [Units]. Parsing never fails. Units which aren't known are
+/// preserved as [Unit::Unknown].
+///
+/// [Units]: super::Units
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Unit {
+    Temperature(TemperatureUnit),
+    Speed(SpeedUnit),
+    Length(LengthUnit),
+    Pressure(PressureUnit),
+    Percent,
+    Degrees,
+    /// Used for values without a unit, such as the UV index.
+    Dimensionless,
+    Unknown(Box<str>),
+}
+
+impl Unit {
+    /// Returns the symbol of the unit, e.g. "°C" or "m/s". Unknown units are returned as they are.
+    pub fn symbol(&self) -> &str {
+        match self {
+            Unit::Temperature(unit) => unit.symbol(),
+            Unit::Speed(unit) => unit.symbol(),
+            Unit::Length(unit) => unit.symbol(),
+            Unit::Pressure(unit) => unit.symbol(),
+            Unit::Percent => "%",
+            Unit::Degrees => "°",
+            Unit::Dimensionless => "",
+            Unit::Unknown(unit) => unit,
+        }
+    }
+
+    pub fn temperature(&self) -> Option<TemperatureUnit> {
+        match self {
+            Unit::Temperature(unit) => Some(*unit),
+            _ => None,
+        }
+    }
+
+    pub fn speed(&self) -> Option<SpeedUnit> {
+        match self {
+            Unit::Speed(unit) => Some(*unit),
+            _ => None,
+        }
+    }
+
+    pub fn length(&self) -> Option<LengthUnit> {
+        match self {
+            Unit::Length(unit) => Some(*unit),
+            _ => None,
+        }
+    }
+
+    pub fn pressure(&self) -> Option<PressureUnit> {
+        match self {
+            Unit::Pressure(unit) => Some(*unit),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Unit {
+    type Err = Infallible;
+
+    fn from_str(unit: &str) -> Result<Self, Self::Err> {
+        Ok(match unit {
+            "celsius" => Unit::Temperature(TemperatureUnit::Celsius),
+            "fahrenheit" => Unit::Temperature(TemperatureUnit::Fahrenheit),
+            "kelvin" => Unit::Temperature(TemperatureUnit::Kelvin),
+            "m/s" => Unit::Speed(SpeedUnit::MetersPerSecond),
+            "km/h" => Unit::Speed(SpeedUnit::KilometersPerHour),
+            "mph" => Unit::Speed(SpeedUnit::MilesPerHour),
+            "knots" => Unit::Speed(SpeedUnit::Knots),
+            "m" => Unit::Length(LengthUnit::Meters),
+            "mm" => Unit::Length(LengthUnit::Millimeters),
+            "in" => Unit::Length(LengthUnit::Inches),
+            "hPa" => Unit::Pressure(PressureUnit::Hectopascals),
+            "inHg" => Unit::Pressure(PressureUnit::InchesOfMercury),
+            "%" => Unit::Percent,
+            "degrees" => Unit::Degrees,
+            "1" => Unit::Dimensionless,
+            unit => Unit::Unknown(unit.into()),
+        })
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+impl<'de> Deserialize<'de> for Unit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let unit = Cow::<str>::deserialize(deserializer)?;
+        Ok(unit.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TemperatureUnit {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+}
+
+impl TemperatureUnit {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°F",
+            TemperatureUnit::Kelvin => "K",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpeedUnit {
+    MetersPerSecond,
+    KilometersPerHour,
+    MilesPerHour,
+    Knots,
+}
+
+impl SpeedUnit {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            SpeedUnit::MetersPerSecond => "m/s",
+            SpeedUnit::KilometersPerHour => "km/h",
+            SpeedUnit::MilesPerHour => "mph",
+            SpeedUnit::Knots => "kn",
+        }
+    }
+
+    /// How many meters per second is one of this unit.
+    fn meters_per_second(&self) -> f64 {
+        match self {
+            SpeedUnit::MetersPerSecond => 1.0,
+            SpeedUnit::KilometersPerHour => 1.0 / 3.6,
+            SpeedUnit::MilesPerHour => 0.44704,
+            SpeedUnit::Knots => 1852.0 / 3600.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LengthUnit {
+    Millimeters,
+    Inches,
+    Meters,
+    Feet,
+}
+
+impl LengthUnit {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            LengthUnit::Millimeters => "mm",
+            LengthUnit::Inches => "in",
+            LengthUnit::Meters => "m",
+            LengthUnit::Feet => "ft",
+        }
+    }
+
+    /// How many millimeters is one of this unit.
+    fn millimeters(&self) -> f64 {
+        match self {
+            LengthUnit::Millimeters => 1.0,
+            LengthUnit::Inches => 25.4,
+            LengthUnit::Meters => 1000.0,
+            LengthUnit::Feet => 304.8,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PressureUnit {
+    Hectopascals,
+    InchesOfMercury,
+}
+
+impl PressureUnit {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            PressureUnit::Hectopascals => "hPa",
+            PressureUnit::InchesOfMercury => "inHg",
+        }
+    }
+
+    /// How many hectopascals is one of this unit.
+    fn hectopascals(&self) -> f64 {
+        match self {
+            PressureUnit::Hectopascals => 1.0,
+            PressureUnit::InchesOfMercury => 33.863_886_666,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DensityUnit {
+    GramsPerCubicMeter,
+    KilogramsPerCubicMeter,
+}
+
+impl DensityUnit {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            DensityUnit::GramsPerCubicMeter => "g/m³",
+            DensityUnit::KilogramsPerCubicMeter => "kg/m³",
+        }
+    }
+
+    /// How many grams per cubic meter is one of this unit.
+    fn grams_per_cubic_meter(&self) -> f64 {
+        match self {
+            DensityUnit::GramsPerCubicMeter => 1.0,
+            DensityUnit::KilogramsPerCubicMeter => 1000.0,
+        }
+    }
+}
+
+/// Temperature with its unit attached.
+///
+/// Example:
+///
+/// ```
+/// use monsoon::body::{Temperature, TemperatureUnit};
+///
+/// let temperature = Temperature::new(20.0, TemperatureUnit::Celsius);
+/// assert_eq!(temperature.to(TemperatureUnit::Fahrenheit).value, 68.0);
+/// assert_eq!(format!("{:.1}", temperature), "20.0 °C");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Temperature {
+    pub value: f64,
+    pub unit: TemperatureUnit,
+}
+
+impl Temperature {
+    pub fn new(value: f64, unit: TemperatureUnit) -> Self {
+        Self { value, unit }
+    }
+
+    /// Converts the temperature to the given unit.
+    pub fn to(self, unit: TemperatureUnit) -> Self {
+        let celsius = self.celsius();
+
+        let value = match unit {
+            TemperatureUnit::Celsius => celsius,
+            TemperatureUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+            TemperatureUnit::Kelvin => celsius + 273.15,
+        };
+
+        Self::new(value, unit)
+    }
+
+    /// Returns the value in degrees Celsius.
+    pub fn celsius(&self) -> f64 {
+        match self.unit {
+            TemperatureUnit::Celsius => self.value,
+            TemperatureUnit::Fahrenheit => (self.value - 32.0) * 5.0 / 9.0,
+            TemperatureUnit::Kelvin => self.value - 273.15,
+        }
+    }
+
+    /// Returns the value in degrees Fahrenheit.
+    pub fn fahrenheit(&self) -> f64 {
+        self.to(TemperatureUnit::Fahrenheit).value
+    }
+
+    /// Returns the value in Kelvin.
+    pub fn kelvin(&self) -> f64 {
+        self.to(TemperatureUnit::Kelvin).value
+    }
+}
+
+impl fmt::Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_value(f, self.value, self.unit.symbol())
+    }
+}
+
+/// Speed with its unit attached.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Speed {
+    pub value: f64,
+    pub unit: SpeedUnit,
+}
+
+impl Speed {
+    pub fn new(value: f64, unit: SpeedUnit) -> Self {
+        Self { value, unit }
+    }
+
+    /// Converts the speed to the given unit.
+    pub fn to(self, unit: SpeedUnit) -> Self {
+        Self::new(self.meters_per_second() / unit.meters_per_second(), unit)
+    }
+
+    /// Returns the value in meters per second.
+    pub fn meters_per_second(&self) -> f64 {
+        self.value * self.unit.meters_per_second()
+    }
+
+    /// Returns the value in kilometers per hour.
+    pub fn kilometers_per_hour(&self) -> f64 {
+        self.to(SpeedUnit::KilometersPerHour).value
+    }
+
+    /// Returns the value in miles per hour.
+    pub fn miles_per_hour(&self) -> f64 {
+        self.to(SpeedUnit::MilesPerHour).value
+    }
+
+    /// Returns the value in knots.
+    pub fn knots(&self) -> f64 {
+        self.to(SpeedUnit::Knots).value
+    }
+
+    /// Returns the number on the Beaufort scale (0 - 12).
+    pub fn beaufort(&self) -> u8 {
+        // Lower bounds of the individual numbers in m/s
+        const SCALE: [f64; 12] = [
+            0.5, 1.6, 3.4, 5.5, 8.0, 10.8, 13.9, 17.2, 20.8, 24.5, 28.5, 32.7,
+        ];
+
+        let speed = self.meters_per_second();
+        SCALE.iter().take_while(|bound| speed >= **bound).count() as u8
+    }
+}
+
+impl fmt::Display for Speed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_value(f, self.value, self.unit.symbol())
+    }
+}
+
+/// Length, such as the amount of precipitation, with its unit attached.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Length {
+    pub value: f64,
+    pub unit: LengthUnit,
+}
+
+impl Length {
+    pub fn new(value: f64, unit: LengthUnit) -> Self {
+        Self { value, unit }
+    }
+
+    /// Converts the length to the given unit.
+    pub fn to(self, unit: LengthUnit) -> Self {
+        Self::new(self.millimeters() / unit.millimeters(), unit)
+    }
+
+    /// Returns the value in millimeters.
+    pub fn millimeters(&self) -> f64 {
+        self.value * self.unit.millimeters()
+    }
+
+    /// Returns the value in inches.
+    pub fn inches(&self) -> f64 {
+        self.to(LengthUnit::Inches).value
+    }
+
+    /// Returns the value in meters.
+    pub fn meters(&self) -> f64 {
+        self.to(LengthUnit::Meters).value
+    }
+
+    /// Returns the value in feet.
+    pub fn feet(&self) -> f64 {
+        self.to(LengthUnit::Feet).value
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_value(f, self.value, self.unit.symbol())
+    }
+}
+
+/// Air pressure with its unit attached.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pressure {
+    pub value: f64,
+    pub unit: PressureUnit,
+}
+
+impl Pressure {
+    pub fn new(value: f64, unit: PressureUnit) -> Self {
+        Self { value, unit }
+    }
+
+    /// Converts the pressure to the given unit.
+    pub fn to(self, unit: PressureUnit) -> Self {
+        Self::new(self.hectopascals() / unit.hectopascals(), unit)
+    }
+
+    /// Returns the value in hectopascals.
+    pub fn hectopascals(&self) -> f64 {
+        self.value * self.unit.hectopascals()
+    }
+
+    /// Returns the value in inches of mercury.
+    pub fn inches_of_mercury(&self) -> f64 {
+        self.to(PressureUnit::InchesOfMercury).value
+    }
+}
+
+impl fmt::Display for Pressure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_value(f, self.value, self.unit.symbol())
+    }
+}
+
+/// Density, such as the absolute humidity, with its unit attached.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Density {
+    pub value: f64,
+    pub unit: DensityUnit,
+}
+
+impl Density {
+    pub fn new(value: f64, unit: DensityUnit) -> Self {
+        Self { value, unit }
+    }
+
+    /// Converts the density to the given unit.
+    pub fn to(self, unit: DensityUnit) -> Self {
+        Self::new(
+            self.grams_per_cubic_meter() / unit.grams_per_cubic_meter(),
+            unit,
+        )
+    }
+
+    /// Returns the value in grams per cubic meter.
+    pub fn grams_per_cubic_meter(&self) -> f64 {
+        self.value * self.unit.grams_per_cubic_meter()
+    }
+
+    /// Returns the value in kilograms per cubic meter.
+    pub fn kilograms_per_cubic_meter(&self) -> f64 {
+        self.to(DensityUnit::KilogramsPerCubicMeter).value
+    }
+}
+
+impl fmt::Display for Density {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_value(f, self.value, self.unit.symbol())
+    }
+}
+
+fn fmt_value(f: &mut fmt::Formatter<'_>, value: f64, symbol: &str) -> fmt::Result {
+    match f.precision() {
+        Some(precision) => write!(f, "{:.*} {}", precision, value, symbol),
+        None => write!(f, "{} {}", value, symbol),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Length, LengthUnit, Pressure, PressureUnit, Speed, SpeedUnit, Temperature, TemperatureUnit,
+        Unit,
+    };
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 0.001, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn parses_units() {
+        assert_eq!(
+            "celsius".parse::<Unit>().unwrap(),
+            Unit::Temperature(TemperatureUnit::Celsius)
+        );
+        assert_eq!(
+            "m/s".parse::<Unit>().unwrap(),
+            Unit::Speed(SpeedUnit::MetersPerSecond)
+        );
+        assert_eq!("1".parse::<Unit>().unwrap(), Unit::Dimensionless);
+        assert_eq!("lux".parse::<Unit>().unwrap(), Unit::Unknown("lux".into()));
+    }
+
+    #[test]
+    fn converts_temperature() {
+        let temperature = Temperature::new(-40.0, TemperatureUnit::Celsius);
+        assert_close(temperature.fahrenheit(), -40.0);
+        assert_close(temperature.kelvin(), 233.15);
+
+        let temperature = Temperature::new(300.0, TemperatureUnit::Kelvin);
+        assert_close(temperature.to(TemperatureUnit::Fahrenheit).value, 80.33);
+    }
+
+    #[test]
+    fn converts_speed() {
+        let speed = Speed::new(10.0, SpeedUnit::MetersPerSecond);
+        assert_close(speed.kilometers_per_hour(), 36.0);
+        assert_close(speed.miles_per_hour(), 22.369);
+        assert_close(speed.knots(), 19.438);
+        assert_close(
+            Speed::new(36.0, SpeedUnit::KilometersPerHour).knots(),
+            19.438,
+        );
+    }
+
+    #[test]
+    fn converts_speed_to_beaufort() {
+        for (speed, beaufort) in [(0.0, 0), (0.5, 1), (3.3, 2), (10.8, 6), (40.0, 12)] {
+            assert_eq!(
+                Speed::new(speed, SpeedUnit::MetersPerSecond).beaufort(),
+                beaufort
+            );
+        }
+
+        assert_eq!(Speed::new(40.0, SpeedUnit::Knots).beaufort(), 8);
+    }
+
+    #[test]
+    fn converts_length_and_pressure() {
+        assert_close(Length::new(25.4, LengthUnit::Millimeters).inches(), 1.0);
+        assert_close(Length::new(1000.0, LengthUnit::Meters).feet(), 3280.84);
+        assert_close(
+            Pressure::new(1013.25, PressureUnit::Hectopascals).inches_of_mercury(),
+            29.921,
+        );
+    }
+
+    #[test]
+    fn displays_value_with_unit() {
+        let speed = Speed::new(3.45, SpeedUnit::MetersPerSecond);
+        assert_eq!(speed.to_string(), "3.45 m/s");
+        assert_eq!(format!("{:.1}", speed), "3.5 m/s");
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt;
+
+use super::{InstantDetails, Speed, Unit, Units};
+
+/// Wind at a single point in time. Follows the meteorological convention where the direction is
+/// the one the wind blows _from_ (in degrees, clockwise from north).
+///
+/// Example:
+///
+/// ```
+/// use monsoon::body::{CompassPoints, Speed, SpeedUnit, Wind};
+///
+/// let wind = Wind::new(225.0, Speed::new(6.0, SpeedUnit::MetersPerSecond));
+/// assert_eq!(wind.compass(CompassPoints::Sixteen), "SW");
+/// assert_eq!(wind.arrow(), '↗');
+/// assert_eq!(wind.beaufort().description(), "Moderate breeze");
+/// assert_eq!(format!("{:.1}", wind), "SW 6.0 m/s");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Wind {
+    pub from_direction: f64,
+    pub speed: Speed,
+}
+
+impl Wind {
+    /// Creates a new wind blowing from the given direction.
+    pub fn new(from_direction: f64, speed: Speed) -> Self {
+        Self {
+            from_direction: from_direction.rem_euclid(360.0),
+            speed,
+        }
+    }
+
+    /// Creates a new wind blowing towards the given direction.
+    pub fn with_to_direction(to_direction: f64, speed: Speed) -> Self {
+        Self::new(to_direction + 180.0, speed)
+    }
+
+    /// Returns the direction the wind blows towards.
+    pub fn to_direction(&self) -> f64 {
+        (self.from_direction + 180.0).rem_euclid(360.0)
+    }
+
+    /// Returns the name of the compass point the wind blows from, e.g. "NE" or "NbE".
+    pub fn compass(&self, points: CompassPoints) -> &'static str {
+        #[rustfmt::skip]
+        const NAMES: [&str; 32] = [
+            "N", "NbE", "NNE", "NEbN", "NE", "NEbE", "ENE", "EbN",
+            "E", "EbS", "ESE", "SEbE", "SE", "SEbS", "SSE", "SbE",
+            "S", "SbW", "SSW", "SWbS", "SW", "SWbW", "WSW", "WbS",
+            "W", "WbN", "WNW", "NWbW", "NW", "NWbN", "NNW", "NbW",
+        ];
+
+        let count = points.count();
+        NAMES[sector(self.from_direction, count) * (NAMES.len() / count)]
+    }
+
+    /// Returns an arrow pointing in the direction the wind blows towards.
+    pub fn arrow(&self) -> char {
+        const ARROWS: [char; 8] = ['↑', '↗', '→', '↘', '↓', '↙', '←', '↖'];
+
+        ARROWS[sector(self.to_direction(), ARROWS.len())]
+    }
+
+    pub fn beaufort(&self) -> Beaufort {
+        Beaufort::from(self.speed)
+    }
+}
+
+impl fmt::Display for Wind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.compass(CompassPoints::Sixteen))?;
+        fmt::Display::fmt(&self.speed, f)
+    }
+}
+
+/// Returns the index of the sector the direction falls into when the circle is divided into
+/// `count` sectors centered around the first one at north.
+fn sector(direction: f64, count: usize) -> usize {
+    let width = 360.0 / count as f64;
+    (direction.rem_euclid(360.0) / width).round() as usize % count
+}
+
+/// Number of points of the compass used by [Wind::compass].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompassPoints {
+    Eight,
+    Sixteen,
+    ThirtyTwo,
+}
+
+impl CompassPoints {
+    fn count(self) -> usize {
+        match self {
+            CompassPoints::Eight => 8,
+            CompassPoints::Sixteen => 16,
+            CompassPoints::ThirtyTwo => 32,
+        }
+    }
+}
+
+/// Wind force on the [Beaufort scale](https://en.wikipedia.org/wiki/Beaufort_scale).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Beaufort(u8);
+
+impl Beaufort {
+    /// Returns the number on the scale (0 - 12).
+    pub fn number(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns the description of the wind force, e.g. "Gentle breeze".
+    pub fn description(&self) -> &'static str {
+        match self.0 {
+            0 => "Calm",
+            1 => "Light air",
+            2 => "Light breeze",
+            3 => "Gentle breeze",
+            4 => "Moderate breeze",
+            5 => "Fresh breeze",
+            6 => "Strong breeze",
+            7 => "Near gale",
+            8 => "Gale",
+            9 => "Strong gale",
+            10 => "Storm",
+            11 => "Violent storm",
+            _ => "Hurricane force",
+        }
+    }
+}
+
+impl From<Speed> for Beaufort {
+    fn from(speed: Speed) -> Self {
+        Self(speed.beaufort())
+    }
+}
+
+impl fmt::Display for Beaufort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+impl InstantDetails {
+    /// Returns the wind if both the direction and speed are known.
+    pub fn wind(&self, units: &Units) -> Option<Wind> {
+        if units.wind_from_direction != Some(Unit::Degrees) {
+            return None;
+        }
+
+        Some(Wind::new(
+            self.wind_from_direction?,
+            self.wind_speed_with_unit(units)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CompassPoints, Wind};
+    use crate::body::{Speed, SpeedUnit};
+
+    fn wind(from_direction: f64) -> Wind {
+        Wind::new(from_direction, Speed::new(1.0, SpeedUnit::MetersPerSecond))
+    }
+
+    #[test]
+    fn returns_compass_points() {
+        assert_eq!(wind(0.0).compass(CompassPoints::Eight), "N");
+        assert_eq!(wind(350.0).compass(CompassPoints::Eight), "N");
+        assert_eq!(wind(350.0).compass(CompassPoints::Sixteen), "N");
+        assert_eq!(wind(350.0).compass(CompassPoints::ThirtyTwo), "NbW");
+        assert_eq!(wind(22.5).compass(CompassPoints::Sixteen), "NNE");
+        assert_eq!(wind(135.0).compass(CompassPoints::Eight), "SE");
+        assert_eq!(wind(-90.0).compass(CompassPoints::Sixteen), "W");
+    }
+
+    #[test]
+    fn converts_directions() {
+        let wind = Wind::with_to_direction(90.0, Speed::new(1.0, SpeedUnit::MetersPerSecond));
+        assert_eq!(wind.from_direction, 270.0);
+        assert_eq!(wind.to_direction(), 90.0);
+        assert_eq!(wind.arrow(), '→');
+    }
+
+    #[test]
+    fn returns_beaufort() {
+        let wind = Wind::new(0.0, Speed::new(40.0, SpeedUnit::Knots));
+        assert_eq!(wind.beaufort().number(), 8);
+        assert_eq!(wind.beaufort().to_string(), "Gale");
+    }
+}
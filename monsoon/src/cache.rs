@@ -0,0 +1,310 @@
+//! Caching of responses. See [MonsoonBuilder::cache] and [MonsoonBuilder::cache_store].
+//!
+//! [MonsoonBuilder::cache]: crate::MonsoonBuilder::cache
+//! [MonsoonBuilder::cache_store]: crate::MonsoonBuilder::cache_store
+use std::{collections::HashMap, fmt::Debug, sync::Mutex};
+
+use crate::{Endpoint, Params, Response};
+
+/// Storage of responses consulted before going to the network. Implementations are expected to
+/// be cheap to call and shouldn't fail; a store which can't serve a response should simply return
+/// `None`.
+pub trait CacheStore: Debug + Send + Sync {
+    /// Returns the stored response for the given key, if any. The response is returned even if it
+    /// has already expired so that it can be revalidated.
+    fn get(&self, key: &CacheKey) -> Option<Response>;
+
+    /// Stores the response under the given key, replacing the previous one.
+    fn insert(&self, key: CacheKey, response: Response);
+}
+
+/// Identifies a cached response. The coordinates are stored in the same precision as used by
+/// [Params].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    lat: i32,
+    lon: i32,
+    alt: Option<i32>,
+    endpoint: Endpoint,
+}
+
+impl From<&Params> for CacheKey {
+    fn from(params: &Params) -> Self {
+        Self {
+            lat: (params.lat * 10000.0).round() as i32,
+            lon: (params.lon * 10000.0).round() as i32,
+            alt: params.alt,
+            endpoint: params.endpoint,
+        }
+    }
+}
+
+impl CacheKey {
+    pub fn lat(&self) -> f64 {
+        f64::from(self.lat) / 10000.0
+    }
+
+    pub fn lon(&self) -> f64 {
+        f64::from(self.lon) / 10000.0
+    }
+
+    pub fn alt(&self) -> Option<i32> {
+        self.alt
+    }
+
+    pub fn endpoint(&self) -> Endpoint {
+        self.endpoint
+    }
+}
+
+/// In-memory cache of responses. Once the capacity is reached, the least recently used entry is
+/// evicted.
+#[derive(Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    inner: Mutex<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    entries: HashMap<CacheKey, Entry>,
+    tick: u64,
+}
+
+#[derive(Debug)]
+struct Entry {
+    response: Response,
+    last_used: u64,
+}
+
+impl MemoryCache {
+    /// Creates a new cache holding at most `capacity` responses.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::default(),
+        }
+    }
+}
+
+impl CacheStore for MemoryCache {
+    fn get(&self, key: &CacheKey) -> Option<Response> {
+        let mut inner = self.inner.lock().expect("poisoned cache lock");
+        inner.tick += 1;
+
+        let tick = inner.tick;
+        inner.entries.get_mut(key).map(|entry| {
+            entry.last_used = tick;
+            entry.response.clone()
+        })
+    }
+
+    fn insert(&self, key: CacheKey, response: Response) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut inner = self.inner.lock().expect("poisoned cache lock");
+        inner.tick += 1;
+
+        if !inner.entries.contains_key(&key) && inner.entries.len() >= self.capacity {
+            if let Some(oldest) = inner
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| *key)
+            {
+                inner.entries.remove(&oldest);
+            }
+        }
+
+        let last_used = inner.tick;
+        inner.entries.insert(
+            key,
+            Entry {
+                response,
+                last_used,
+            },
+        );
+    }
+}
+
+#[cfg(feature = "file-cache")]
+pub use file::FileCache;
+
+#[cfg(feature = "file-cache")]
+mod file {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+        process,
+        sync::atomic::{AtomicU64, Ordering},
+    };
+
+    use super::{CacheKey, CacheStore};
+    use crate::{Endpoint, Error, Response, Result};
+
+    /// Distinguishes the temporary files of concurrent writes within the process.
+    static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    /// Cache storing responses as JSON files in a directory so that they survive process
+    /// restarts. Every response is stored in its own file named after the key. The files are
+    /// accessed synchronously.
+    #[derive(Debug, Clone)]
+    pub struct FileCache {
+        dir: PathBuf,
+    }
+
+    impl FileCache {
+        /// Creates a new cache in the given directory. The directory is created if it doesn't
+        /// exist.
+        pub fn new(dir: impl Into<PathBuf>) -> Result<Self> {
+            let dir = dir.into();
+            fs::create_dir_all(&dir).map_err(Error::Cache)?;
+
+            Ok(Self { dir })
+        }
+
+        pub fn dir(&self) -> &Path {
+            &self.dir
+        }
+
+        fn path(&self, key: &CacheKey) -> PathBuf {
+            let endpoint = match key.endpoint {
+                Endpoint::Complete => "complete",
+                Endpoint::Compact => "compact",
+            };
+
+            let alt = key
+                .alt
+                .map(|alt| alt.to_string())
+                .unwrap_or_else(|| "none".into());
+
+            self.dir
+                .join(format!("{}_{}_{}_{}.json", endpoint, key.lat, key.lon, alt))
+        }
+    }
+
+    impl CacheStore for FileCache {
+        fn get(&self, key: &CacheKey) -> Option<Response> {
+            let contents = fs::read(self.path(key)).ok()?;
+            serde_json::from_slice(&contents).ok()
+        }
+
+        fn insert(&self, key: CacheKey, response: Response) {
+            let Ok(contents) = serde_json::to_vec(&response) else {
+                return;
+            };
+
+            // Write into a temporary file first so that readers never see a partial response. The
+            // name is unique so that concurrent writers, even from other processes, don't clash.
+            let path = self.path(&key);
+            let tmp_path = path.with_extension(format!(
+                "json.{}.{}.tmp",
+                process::id(),
+                TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+            ));
+
+            if fs::write(&tmp_path, contents).is_err() || fs::rename(&tmp_path, &path).is_err() {
+                _ = fs::remove_file(&tmp_path);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+
+    use super::{CacheKey, CacheStore, MemoryCache};
+    use crate::{Params, Response};
+
+    fn key(lat: f64) -> CacheKey {
+        CacheKey::from(&Params::new(lat, 14.4207, None).unwrap())
+    }
+
+    fn response(body: &str) -> Response {
+        Response::new(
+            DateTime::parse_from_rfc2822("Tue, 21 Mar 2023 10:00:00 GMT").unwrap(),
+            "Tue, 21 Mar 2023 09:00:00 GMT".into(),
+            body.into(),
+        )
+    }
+
+    #[test]
+    fn evicts_least_recently_used_entry() {
+        let cache = MemoryCache::new(2);
+
+        cache.insert(key(50.0), response("a"));
+        cache.insert(key(51.0), response("b"));
+        assert!(cache.get(&key(50.0)).is_some());
+
+        cache.insert(key(52.0), response("c"));
+        assert!(cache.get(&key(50.0)).is_some());
+        assert!(cache.get(&key(51.0)).is_none());
+        assert!(cache.get(&key(52.0)).is_some());
+    }
+
+    #[test]
+    fn replaces_existing_entry() {
+        let cache = MemoryCache::new(1);
+
+        cache.insert(key(50.0), response("a"));
+        cache.insert(key(50.0), response("b"));
+        assert_eq!(&*cache.get(&key(50.0)).unwrap().raw_body, "b");
+    }
+
+    #[cfg(feature = "file-cache")]
+    #[test]
+    fn persists_responses_in_files() {
+        use super::FileCache;
+
+        let dir = std::env::temp_dir().join(format!("monsoon-cache-{}", std::process::id()));
+
+        let cache = FileCache::new(&dir).unwrap();
+        assert!(cache.get(&key(50.0)).is_none());
+
+        cache.insert(key(50.0), response("a"));
+
+        // A fresh instance sees the same data
+        let cache = FileCache::new(&dir).unwrap();
+        let cached = cache.get(&key(50.0)).unwrap();
+        assert_eq!(&*cached.raw_body, "a");
+        assert_eq!(cached.last_modified(), "Tue, 21 Mar 2023 09:00:00 GMT");
+        assert_eq!(
+            cached.expires_at(),
+            &DateTime::parse_from_rfc2822("Tue, 21 Mar 2023 10:00:00 GMT").unwrap()
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "file-cache")]
+    #[test]
+    fn handles_concurrent_writes() {
+        use super::FileCache;
+
+        let dir =
+            std::env::temp_dir().join(format!("monsoon-cache-concurrent-{}", std::process::id()));
+        let cache = FileCache::new(&dir).unwrap();
+
+        std::thread::scope(|scope| {
+            for body in ["a", "b", "c", "d"] {
+                let cache = &cache;
+                scope.spawn(move || {
+                    for _ in 0..25 {
+                        cache.insert(key(50.0), response(body));
+                    }
+                });
+            }
+        });
+
+        let cached = cache.get(&key(50.0)).unwrap();
+        assert!(["a", "b", "c", "d"].contains(&&*cached.raw_body));
+
+        // No temporary files are left behind
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,89 +1,122 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, sync::Arc, time::Duration};
 
 use chrono::{DateTime, FixedOffset, Utc};
-use reqwest::{
-    header::{HeaderMap, HeaderValue, IF_MODIFIED_SINCE},
-    StatusCode, Url,
+use http::{
+    header::{HeaderMap, HeaderValue, EXPIRES, IF_MODIFIED_SINCE, RETRY_AFTER, USER_AGENT},
+    StatusCode,
 };
+use url::Url;
 
-use crate::{Error, Params, Response, Result};
+use crate::{
+    rate_limit::RateLimiter,
+    transport::{Transport, TransportResponse},
+    Error, HttpError, RateLimit, Response, Result, RetryPolicy,
+};
+
+/// Request to one of the APIs.
+pub trait Request {
+    /// Returns the path of the API relative to the base URL.
+    fn path(&self) -> &'static str;
+
+    /// Returns the query parameters of the request.
+    fn query(&self) -> Vec<(&'static str, String)>;
+
+    /// Returns the previous response used to avoid downloading the same data again.
+    fn last_response(&self) -> Option<&Response>;
+}
 
 #[derive(Debug, Clone)]
 pub struct Client {
-    client: reqwest::Client,
+    transport: Arc<dyn Transport>,
+    user_agent: HeaderValue,
+    base_url: Url,
+    retry: Option<RetryPolicy>,
+    rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 impl Client {
-    pub fn new(user_agent: Cow<'static, str>) -> Result<Self> {
-        let client = reqwest::Client::builder()
-            .user_agent(user_agent.as_ref())
-            .build()
-            .map_err(generalize_error)?;
+    pub fn new(
+        transport: Arc<dyn Transport>,
+        user_agent: Cow<'static, str>,
+        base_url: Url,
+        retry: Option<RetryPolicy>,
+        rate_limit: Option<RateLimit>,
+    ) -> Result<Self> {
+        let user_agent = HeaderValue::from_str(&user_agent)
+            .map_err(|_| Error::Params("Invalid user agent value."))?;
 
-        Ok(Self { client })
+        Ok(Self {
+            transport,
+            user_agent,
+            base_url,
+            retry,
+            rate_limiter: rate_limit.map(|limit| Arc::new(RateLimiter::new(&limit))),
+        })
     }
 
-    pub async fn get(&self, params: Params) -> Result<Response> {
-        if let Some(last_response) = &params.last_response {
+    pub async fn get(&self, request: &impl Request) -> Result<Response> {
+        if let Some(last_response) = request.last_response() {
             if last_response.expires_at() > &Utc::now() {
-                return Ok(params.last_response.unwrap());
+                return Ok(last_response.clone());
             }
         }
 
-        self.get_from_api(params).await
+        let mut attempt = 1;
+        loop {
+            match self.get_from_api(request).await {
+                Err(err) => match self
+                    .retry
+                    .as_ref()
+                    .and_then(|retry| retry.backoff(attempt, &err))
+                {
+                    Some(backoff) => {
+                        tokio::time::sleep(backoff).await;
+                        attempt += 1;
+                    }
+                    None => return Err(err),
+                },
+                response => return response,
+            }
+        }
     }
 
-    async fn get_from_api(&self, params: Params) -> Result<Response> {
+    async fn get_from_api(&self, request: &impl Request) -> Result<Response> {
+        // Held until the whole body is read
+        let _permit = match &self.rate_limiter {
+            Some(rate_limiter) => Some(rate_limiter.acquire().await),
+            None => None,
+        };
+
         let response = {
-            let url = create_url(&params);
-            let headers = create_headers(&params)?;
+            let url = create_url(&self.base_url, request);
+            let headers = create_headers(&self.user_agent, request)?;
 
-            self.client
-                .get(url)
-                .headers(headers)
-                .send()
+            self.transport
+                .get(url, headers)
                 .await
-                .map_err(generalize_error)?
+                .map_err(Error::HttpClient)?
         };
 
-        match response.error_for_status() {
-            Ok(response) => match response.status() {
-                StatusCode::OK => handle_ok_response(response).await,
-                StatusCode::NOT_MODIFIED => handle_not_modified_response(params, response).await,
-                StatusCode::TOO_MANY_REQUESTS => {
-                    Err(Error::Response("Too many requests (HTTP 429)".into()))
-                }
-                code => Err(Error::Response(
-                    format!("Unexpected error code: {}", code).into(),
-                )),
-            },
-            Err(err) => Err(Error::Response(err.to_string().into())),
+        match response.status {
+            StatusCode::OK => handle_ok_response(response),
+            StatusCode::NOT_MODIFIED => handle_not_modified_response(request, &response),
+            _ => Err(handle_error_response(&response)),
         }
     }
 }
 
-fn create_url(params: &Params) -> Url {
-    let mut url = Url::parse_with_params(
-        "https://api.met.no/weatherapi/locationforecast/2.0/complete",
-        &[
-            ("lat", params.lat.to_string()),
-            ("lon", params.lon.to_string()),
-        ],
-    )
-    .expect("valid URL");
-
-    if let Some(alt) = params.alt {
-        url.query_pairs_mut()
-            .append_pair("altitude", &alt.to_string());
-    }
+fn create_url(base_url: &Url, request: &impl Request) -> Url {
+    let mut url = base_url.join(request.path()).expect("valid URL");
+    url.query_pairs_mut().extend_pairs(request.query());
 
     url
 }
 
-fn create_headers(params: &Params) -> Result<HeaderMap> {
+fn create_headers(user_agent: &HeaderValue, request: &impl Request) -> Result<HeaderMap> {
     let mut map = HeaderMap::new();
+    map.insert(USER_AGENT, user_agent.clone());
 
-    if let Some(last_response) = &params.last_response {
+    if let Some(last_response) = request.last_response() {
         map.append(
             IF_MODIFIED_SINCE,
             HeaderValue::from_str(last_response.last_modified()).map_err(|_| {
@@ -95,17 +128,19 @@ fn create_headers(params: &Params) -> Result<HeaderMap> {
     Ok(map)
 }
 
-fn extract_headers(response: &reqwest::Response) -> Result<(DateTime<FixedOffset>, Box<str>)> {
+fn extract_headers(response: &TransportResponse) -> Result<(DateTime<FixedOffset>, Box<str>)> {
     let expires_at = response
-        .headers()
+        .headers
         .get("expires")
         .ok_or(Error::Response("Missing expires header".into()))?
         .to_str()
         .map_err(|_| Error::Response("Invalid expires header.".into()))?;
 
+    // Not all of the APIs send the header, the time of the response is the next best thing.
     let last_modified = response
-        .headers()
+        .headers
         .get("last-modified")
+        .or_else(|| response.headers.get("date"))
         .ok_or(Error::Response("Missing last-modified header".into()))?
         .to_str()
         .map_err(|_| Error::Response("Invalid last-modified header.".into()))?
@@ -118,34 +153,176 @@ fn extract_headers(response: &reqwest::Response) -> Result<(DateTime<FixedOffset
     ))
 }
 
-async fn handle_ok_response(response: reqwest::Response) -> Result<Response> {
+fn handle_ok_response(response: TransportResponse) -> Result<Response> {
     let (expires_at, last_modified) = extract_headers(&response)?;
-    let raw_body = response
-        .text()
-        .await
-        .map_err(|_| Error::Response("Failed to decode response.".into()))?
-        .into_boxed_str();
+    let raw_body = String::from_utf8(response.body)
+        .map_err(|_| Error::Response("Invalid UTF-8 body.".into()))?
+        .into();
 
     Ok(Response::new(expires_at, last_modified, raw_body))
 }
 
-async fn handle_not_modified_response(
-    params: Params,
-    response: reqwest::Response,
+fn handle_not_modified_response(
+    request: &impl Request,
+    response: &TransportResponse,
 ) -> Result<Response> {
-    let (expires_at, last_modified) = extract_headers(&response)?;
+    let (expires_at, last_modified) = extract_headers(response)?;
 
-    let last_response = params
-        .last_response
-        .expect("304 only with a valid last response");
+    // Any transport may return 304, e.g. one going through a caching proxy
+    let last_response = request.last_response().ok_or(Error::Response(
+        "Unexpected 304 without a previous response.".into(),
+    ))?;
 
     Ok(Response::new(
         expires_at,
         last_modified,
-        last_response.raw_body,
+        last_response.raw_body.clone(),
     ))
 }
 
-fn generalize_error(err: impl ToString) -> Error {
-    Error::HttpClient(err.to_string())
+fn handle_error_response(response: &TransportResponse) -> Error {
+    let headers = &response.headers;
+
+    let err = HttpError::new(
+        response.status,
+        headers.get(RETRY_AFTER).and_then(parse_retry_after),
+        headers
+            .get(EXPIRES)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| DateTime::parse_from_rfc2822(value).ok()),
+    );
+
+    match err.status {
+        StatusCode::TOO_MANY_REQUESTS => Error::TooManyRequests(err),
+        status if status.is_server_error() => Error::Server(err),
+        status if status.is_client_error() => Error::Client(err),
+        _ => Error::UnexpectedStatus(err),
+    }
+}
+
+/// The value is either a number of seconds or a date after which to retry.
+fn parse_retry_after(value: &HeaderValue) -> Option<Duration> {
+    let value = value.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        date.signed_duration_since(Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use http::{
+        header::{HeaderValue, IF_MODIFIED_SINCE, USER_AGENT},
+        StatusCode,
+    };
+    use url::Url;
+
+    use super::{create_url, parse_retry_after, Client};
+    use crate::{
+        transport::fake::{headers, FakeTransport},
+        Endpoint, Error, Params,
+    };
+
+    /// Already expired so that the responses are revalidated.
+    const EXPIRES: &str = "Tue, 21 Mar 2023 09:30:00 GMT";
+
+    fn client(transport: FakeTransport) -> Client {
+        Client::new(
+            Arc::new(transport),
+            "test.com support@test.com".into(),
+            Url::parse("https://api.met.no/weatherapi/").unwrap(),
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn sends_requests_via_transport() {
+        let transport = FakeTransport::default()
+            .respond(StatusCode::OK, headers(EXPIRES), "{}")
+            .respond(StatusCode::NOT_MODIFIED, headers(EXPIRES), "")
+            .respond(StatusCode::UNPROCESSABLE_ENTITY, headers(EXPIRES), "");
+        let client = client(transport.clone());
+
+        let params = Params::new(50.0880, 14.4207, None).unwrap();
+        let response = client.get(&params).await.unwrap();
+        assert_eq!(&*response.raw_body, "{}");
+
+        // Expired in the past, has to be revalidated
+        let params = Params::new_with_last_response(50.0880, 14.4207, None, response).unwrap();
+        let response = client.get(&params).await.unwrap();
+        assert_eq!(&*response.raw_body, "{}");
+
+        let err = client.get(&params).await.unwrap_err();
+        assert!(matches!(err, Error::Client(_)));
+
+        let requests = transport.requests();
+        assert_eq!(
+            requests[0].0.as_str(),
+            "https://api.met.no/weatherapi/locationforecast/2.0/complete?lat=50.088&lon=14.4207"
+        );
+        assert_eq!(requests[0].1[USER_AGENT], "test.com support@test.com");
+        assert_eq!(requests[0].1.get(IF_MODIFIED_SINCE), None);
+        assert_eq!(
+            requests[1].1[IF_MODIFIED_SINCE],
+            "Tue, 21 Mar 2023 09:00:00 GMT"
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_not_modified_without_last_response() {
+        let transport =
+            FakeTransport::default().respond(StatusCode::NOT_MODIFIED, headers(EXPIRES), "");
+        let client = client(transport);
+
+        let params = Params::new(50.0880, 14.4207, None).unwrap();
+        let err = client.get(&params).await.unwrap_err();
+        assert!(matches!(err, Error::Response(_)));
+    }
+
+    #[test]
+    fn creates_url_relative_to_base_url() {
+        let params = Params::new(50.0880, 14.4207, 320).unwrap();
+
+        let base_url = Url::parse("https://api.met.no/weatherapi/").unwrap();
+        assert_eq!(
+            create_url(&base_url, &params).as_str(),
+            "https://api.met.no/weatherapi/locationforecast/2.0/complete?lat=50.088&lon=14.4207&altitude=320"
+        );
+
+        let base_url = Url::parse("http://localhost:8080/mirror/").unwrap();
+        assert_eq!(
+            create_url(&base_url, &params).as_str(),
+            "http://localhost:8080/mirror/locationforecast/2.0/complete?lat=50.088&lon=14.4207&altitude=320"
+        );
+        let mut params = params;
+        params.endpoint = Endpoint::Compact;
+        assert_eq!(
+            create_url(&base_url, &params).as_str(),
+            "http://localhost:8080/mirror/locationforecast/2.0/compact?lat=50.088&lon=14.4207&altitude=320"
+        );
+    }
+
+    #[test]
+    fn parses_retry_after() {
+        assert_eq!(
+            parse_retry_after(&HeaderValue::from_static("120")),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after(&HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT")),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after(&HeaderValue::from_static("soon")), None);
+    }
 }
@@ -1,9 +1,30 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, io::ErrorKind, time::Duration};
+
+use chrono::{DateTime, FixedOffset};
+use http::StatusCode;
+
+use crate::transport::TransportError;
 
 #[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
     #[error("An error occured while working with the Http client.")]
-    HttpClient(String),
+    HttpClient(#[source] TransportError),
+
+    #[error("Too many requests (HTTP 429).")]
+    TooManyRequests(HttpError),
+
+    #[error("The server failed to fulfill the request (HTTP {}).", .0.status.as_u16())]
+    Server(HttpError),
+
+    #[error("The request was rejected by the server (HTTP {}).", .0.status.as_u16())]
+    Client(HttpError),
+
+    #[error("Unexpected status code (HTTP {}).", .0.status.as_u16())]
+    UnexpectedStatus(HttpError),
+
+    #[error("The location is outside of the area covered by the API (HTTP {}).", .0.status.as_u16())]
+    OutsideCoverage(HttpError),
 
     #[error("Invalid or unexpected response.")]
     Response(Cow<'static, str>),
@@ -14,8 +35,121 @@ pub enum Error {
     #[error("Unable to deserialize the JSON body.")]
     ResponseBody(#[from] serde_json::Error),
 
+    #[error("Unable to parse the XML body.")]
+    XmlBody(#[from] roxmltree::Error),
+
     #[error("Invalid params provided.")]
     Params(&'static str),
+
+    #[error("Unable to access the cache.")]
+    Cache(#[source] std::io::Error),
+
+    #[cfg(feature = "blocking")]
+    #[error("Unable to start the runtime for the blocking client.")]
+    Runtime(std::io::Error),
+}
+
+impl Error {
+    /// Returns the details of the unsuccessful HTTP response, if that's what caused the error.
+    pub fn http_error(&self) -> Option<&HttpError> {
+        match self {
+            Error::TooManyRequests(err)
+            | Error::Server(err)
+            | Error::Client(err)
+            | Error::UnexpectedStatus(err)
+            | Error::OutsideCoverage(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns the status code of the unsuccessful HTTP response, if that's what caused the
+    /// error.
+    pub fn status(&self) -> Option<StatusCode> {
+        self.http_error().map(|err| err.status)
+    }
+
+    /// Returns how long to wait before retrying as requested by the server via the
+    /// "Retry-After" header.
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.http_error().and_then(|err| err.retry_after)
+    }
+
+    /// Returns true if the error is likely transient and the same request may succeed when
+    /// retried later. That's the case for connection problems, timeouts, rate limiting and
+    /// temporary server failures.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::HttpClient(err) => is_transient(err.as_ref()),
+            Error::TooManyRequests(_) => true,
+            Error::Server(err) => matches!(
+                err.status,
+                StatusCode::INTERNAL_SERVER_ERROR
+                    | StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            ),
+            _ => false,
+        }
+    }
+}
+
+/// Errors of [ReqwestTransport] are recognized directly. Other transports are expected to report
+/// the connection problems and timeouts via [std::io::Error].
+///
+/// [ReqwestTransport]: crate::transport::ReqwestTransport
+fn is_transient(err: &(dyn std::error::Error + 'static)) -> bool {
+    #[cfg(feature = "reqwest")]
+    if let Some(err) = err.downcast_ref::<reqwest::Error>() {
+        return err.is_timeout() || err.is_connect() || err.is_request() || err.is_body();
+    }
+
+    err.downcast_ref::<std::io::Error>().is_some_and(|err| {
+        matches!(
+            err.kind(),
+            ErrorKind::ConnectionRefused
+                | ErrorKind::ConnectionReset
+                | ErrorKind::ConnectionAborted
+                | ErrorKind::BrokenPipe
+                | ErrorKind::TimedOut
+                | ErrorKind::UnexpectedEof
+                | ErrorKind::Interrupted
+        )
+    })
+}
+
+/// Some of the APIs cover only a limited area and reject other locations with HTTP 422.
+pub(crate) fn outside_coverage(err: Error) -> Error {
+    match err {
+        Error::Client(err) if err.status == StatusCode::UNPROCESSABLE_ENTITY => {
+            Error::OutsideCoverage(err)
+        }
+        err => err,
+    }
+}
+
+/// Details of an unsuccessful HTTP response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct HttpError {
+    pub status: StatusCode,
+    /// Parsed from the "Retry-After" header. Dates are converted to the remaining duration.
+    pub retry_after: Option<Duration>,
+    /// Parsed from the "Expires" header.
+    pub expires: Option<DateTime<FixedOffset>>,
+}
+
+impl HttpError {
+    pub(crate) fn new(
+        status: StatusCode,
+        retry_after: Option<Duration>,
+        expires: Option<DateTime<FixedOffset>>,
+    ) -> Self {
+        Self {
+            status,
+            retry_after,
+            expires,
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -18,13 +18,31 @@
 //! }
 //! ```
 //!
+//! Besides the weather forecast, the short-term precipitation forecast is available via the
+//! [nowcast] module, the weather warnings via the [metalerts] module, the marine conditions via the
+//! [ocean] module, the air quality via the [airquality] module, the regional text forecasts via the
+//! [textforecast] module, the water levels in the harbors via the [tidalwater] module and the times
+//! of sunrise and sunset via the [sunrise] module.
+//!
 //! You're required to properly identify yourself. In this case, the string `"test.com
 //! support@test.com"` will be sent in the `User-Agent` of every request.
 //!
 //! You're further required to a rate limit of 20 requests per second and to respect the "Expires"
-//! header of each response. Monsoon doesn't implement these rules on its own but it does
-//! implement the [Service] trait of [Tower] and as such you can use middleware in the Tower
-//! ecosystem to implement them. See [Examples]. Finally, see the [Terms of Service] for more information.
+//! header of each response. The rate limit is enforced by default (see [RateLimit]) and the latter
+//! is taken care of when the cache is enabled via [MonsoonBuilder::cache]. Monsoon also implements
+//! the [Service] trait of [Tower] and as such you can use middleware in the Tower ecosystem to
+//! customize the behavior further. See [Examples]. A synchronous client, which doesn't require an
+//! async runtime, is available in the `blocking` module behind the `blocking` feature. Finally,
+//! see the [Terms of Service] for more information.
+//!
+//! The requests are sent via [reqwest] by default. Use [MonsoonBuilder::transport] to plug in a
+//! different HTTP stack (see the [transport] module), in which case the `reqwest` feature can be
+//! disabled. The TLS backend of reqwest is selected via the `default-tls` (enabled by default),
+//! `native-tls` and `rustls-tls` features. Disable the default features to use e.g. only rustls:
+//!
+//! ```toml
+//! monsoon = { version = "0.2", default-features = false, features = ["rustls-tls"] }
+//! ```
 //!
 //! [The Norwegian Meteorological Institute]: https://www.met.no/en
 //! [Yr.no]: https://www.yr.no/en
@@ -32,10 +50,27 @@
 //! [Tower]: https://docs.rs/tower/latest/tower
 //! [Examples]: https://github.com/jiripospisil/monsoon/tree/master/monsoon/examples
 //! [Terms of Service]: https://api.met.no/doc/TermsOfService
+//! [reqwest]: https://docs.rs/reqwest
+pub mod airquality;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod body;
+pub mod cache;
 mod client;
 mod error;
+pub mod metalerts;
 mod monsoon;
+pub mod nowcast;
+pub mod ocean;
+mod rate_limit;
+mod retry;
+pub mod sunrise;
+pub mod textforecast;
+pub mod tidalwater;
+pub mod transport;
+mod xml;
 
-pub use crate::monsoon::{Monsoon, Params, Response};
-pub use error::{Error, Result};
+pub use crate::monsoon::{Endpoint, Monsoon, MonsoonBuilder, Params, Response};
+pub use error::{Error, HttpError, Result};
+pub use rate_limit::RateLimit;
+pub use retry::RetryPolicy;
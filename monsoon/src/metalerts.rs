@@ -0,0 +1,533 @@
+//! Official weather warnings as provided by the [MetAlerts API]. The warnings for a location are
+//! listed in a feed (see [Monsoon::get_alerts]) and each of them is available as a [CAP] document
+//! (see [Monsoon::get_alert]).
+//!
+//! Example:
+//!
+//! ```no_run
+//! use monsoon::{metalerts, Monsoon};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+//! let monsoon = Monsoon::new("test.com support@test.com")?;
+//!
+//! let params = metalerts::Params::point(59.9139, 10.7522)?;
+//! let feed = monsoon.get_alerts(params).await?.alert_feed()?;
+//!
+//! for item in feed.items.iter() {
+//!     let alert = monsoon.get_alert(&item.cap).await?.alert()?;
+//!     for info in alert.infos.iter() {
+//!         println!("{}: {:?}", info.event, info.awareness_level);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [MetAlerts API]: https://api.met.no/weatherapi/metalerts/2.0/documentation
+//! [CAP]: https://docs.oasis-open.org/emergency/cap/v1.2/CAP-v1.2.html
+//! [Monsoon::get_alerts]: crate::Monsoon::get_alerts
+//! [Monsoon::get_alert]: crate::Monsoon::get_alert
+use chrono::{DateTime, FixedOffset};
+use roxmltree::{Document, Node};
+
+use crate::{
+    client::Request,
+    monsoon::normalize_coordinates,
+    xml::{child, children, required, text},
+    Error, Response, Result,
+};
+
+/// The location for which the warnings should be looked up. Set [Params::last_response] to avoid
+/// downloading an unchanged feed again.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Params {
+    pub location: Location,
+
+    pub last_response: Option<Response>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Location {
+    Point {
+        lat: f64,
+        lon: f64,
+    },
+    /// The number of a Norwegian county, e.g. "03" for Oslo.
+    County(Box<str>),
+}
+
+impl Params {
+    /// Creates a new Params instance for the given coordinates. They're validated and normalized
+    /// the same way as in [crate::Params::new].
+    pub fn point(lat: f64, lon: f64) -> Result<Self> {
+        let (lat, lon) = normalize_coordinates(lat, lon)?;
+
+        Ok(Self {
+            location: Location::Point { lat, lon },
+            last_response: None,
+        })
+    }
+
+    /// Creates a new Params instance for the given county.
+    pub fn county(county: &str) -> Result<Self> {
+        if county.is_empty() || !county.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(Error::Params("Invalid county value."));
+        }
+
+        Ok(Self {
+            location: Location::County(county.into()),
+            last_response: None,
+        })
+    }
+}
+
+impl Request for Params {
+    fn path(&self) -> &'static str {
+        "metalerts/2.0/current.rss"
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        match &self.location {
+            Location::Point { lat, lon } => {
+                vec![("lat", lat.to_string()), ("lon", lon.to_string())]
+            }
+            Location::County(county) => vec![("county", county.to_string())],
+        }
+    }
+
+    fn last_response(&self) -> Option<&Response> {
+        self.last_response.as_ref()
+    }
+}
+
+/// Request for a single CAP document.
+#[derive(Debug)]
+pub(crate) struct CapRequest<'a> {
+    pub cap: &'a str,
+}
+
+impl Request for CapRequest<'_> {
+    fn path(&self) -> &'static str {
+        "metalerts/2.0/current"
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        vec![("cap", self.cap.to_string())]
+    }
+
+    fn last_response(&self) -> Option<&Response> {
+        None
+    }
+}
+
+impl Response {
+    /// Parses the body of a response from [Monsoon::get_alerts].
+    ///
+    /// [Monsoon::get_alerts]: crate::Monsoon::get_alerts
+    pub fn alert_feed(&self) -> Result<Feed> {
+        Feed::parse(&self.raw_body)
+    }
+
+    /// Parses the body of a response from [Monsoon::get_alert].
+    ///
+    /// [Monsoon::get_alert]: crate::Monsoon::get_alert
+    pub fn alert(&self) -> Result<Alert> {
+        Alert::parse(&self.raw_body)
+    }
+}
+
+/// Feed of the current warnings.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Feed {
+    pub items: Box<[FeedItem]>,
+}
+
+impl Feed {
+    fn parse(raw: &str) -> Result<Self> {
+        let document = Document::parse(raw)?;
+
+        let channel = child(document.root_element(), "channel")
+            .ok_or(Error::Response("Missing channel element.".into()))?;
+
+        let items = children(channel, "item")
+            .map(|item| {
+                let link = text(item, "link");
+                let cap = link
+                    .and_then(cap_from_link)
+                    .or_else(|| text(item, "guid").map(Into::into))
+                    .ok_or(Error::Response("Missing CAP identifier.".into()))?;
+
+                Ok(FeedItem {
+                    title: text(item, "title").unwrap_or_default().into(),
+                    description: text(item, "description").map(Into::into),
+                    link: link.map(Into::into),
+                    cap,
+                    published_at: text(item, "pubDate")
+                        .and_then(|date| DateTime::parse_from_rfc2822(date).ok()),
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { items })
+    }
+}
+
+/// Single warning in the [Feed].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct FeedItem {
+    pub title: Box<str>,
+    pub description: Option<Box<str>>,
+    pub link: Option<Box<str>>,
+    /// Identifier of the CAP document with the details. Pass it to [Monsoon::get_alert].
+    ///
+    /// [Monsoon::get_alert]: crate::Monsoon::get_alert
+    pub cap: Box<str>,
+    pub published_at: Option<DateTime<FixedOffset>>,
+}
+
+fn cap_from_link(link: &str) -> Option<Box<str>> {
+    let url = url::Url::parse(link).ok()?;
+    let (_, cap) = url.query_pairs().find(|(key, _)| key == "cap")?;
+
+    Some(cap.into())
+}
+
+/// Warning parsed from a CAP document.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Alert {
+    pub identifier: Box<str>,
+    pub sent: DateTime<FixedOffset>,
+    /// E.g. "Alert", "Update" or "Cancel".
+    pub msg_type: Box<str>,
+    /// The same warning in different languages.
+    pub infos: Box<[Info]>,
+}
+
+impl Alert {
+    fn parse(raw: &str) -> Result<Self> {
+        let document = Document::parse(raw)?;
+        let alert = document.root_element();
+
+        Ok(Self {
+            identifier: required(alert, "identifier")?.into(),
+            sent: parse_time(required(alert, "sent")?)?,
+            msg_type: required(alert, "msgType")?.into(),
+            infos: children(alert, "info")
+                .map(Info::parse)
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    /// Returns true if the coordinates of the params lie within any of the areas of the warning.
+    pub fn contains(&self, params: &crate::Params) -> bool {
+        self.infos.iter().any(|info| info.contains(params))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Info {
+    pub language: Option<Box<str>>,
+    pub event: Box<str>,
+    pub headline: Option<Box<str>>,
+    pub description: Option<Box<str>>,
+    pub instruction: Option<Box<str>>,
+    pub urgency: Urgency,
+    pub severity: Severity,
+    pub certainty: Certainty,
+    pub awareness_level: Option<AwarenessLevel>,
+    pub onset: Option<DateTime<FixedOffset>>,
+    pub expires: Option<DateTime<FixedOffset>>,
+    pub areas: Box<[Area]>,
+}
+
+impl Info {
+    fn parse(info: Node) -> Result<Self> {
+        let time = |name| text(info, name).map(parse_time).transpose();
+
+        let awareness_level = children(info, "parameter")
+            .find(|parameter| text(*parameter, "valueName") == Some("awareness_level"))
+            .and_then(|parameter| text(parameter, "value"))
+            .and_then(AwarenessLevel::parse);
+
+        Ok(Self {
+            language: text(info, "language").map(Into::into),
+            event: required(info, "event")?.into(),
+            headline: text(info, "headline").map(Into::into),
+            description: text(info, "description").map(Into::into),
+            instruction: text(info, "instruction").map(Into::into),
+            urgency: Urgency::parse(text(info, "urgency")),
+            severity: Severity::parse(text(info, "severity")),
+            certainty: Certainty::parse(text(info, "certainty")),
+            awareness_level,
+            onset: time("onset")?,
+            expires: time("expires")?,
+            areas: children(info, "area").map(Area::parse).collect(),
+        })
+    }
+
+    /// Returns true if the coordinates of the params lie within any of the areas.
+    pub fn contains(&self, params: &crate::Params) -> bool {
+        self.areas.iter().any(|area| area.contains(params))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Area {
+    pub description: Box<str>,
+    pub polygons: Box<[Polygon]>,
+}
+
+impl Area {
+    fn parse(area: Node) -> Self {
+        Self {
+            description: text(area, "areaDesc").unwrap_or_default().into(),
+            polygons: children(area, "polygon")
+                .filter_map(|polygon| Polygon::parse(polygon.text()?))
+                .collect(),
+        }
+    }
+
+    /// Returns true if the coordinates of the params lie within any of the polygons.
+    pub fn contains(&self, params: &crate::Params) -> bool {
+        self.polygons
+            .iter()
+            .any(|polygon| polygon.contains(params.lat, params.lon))
+    }
+}
+
+/// Closed polygon with the points as (lat, lon) pairs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    pub points: Box<[(f64, f64)]>,
+}
+
+impl Polygon {
+    /// The points are separated by whitespace, the coordinates by a comma.
+    fn parse(raw: &str) -> Option<Self> {
+        let points = raw
+            .split_whitespace()
+            .map(|point| {
+                let (lat, lon) = point.split_once(',')?;
+                Some((lat.parse().ok()?, lon.parse().ok()?))
+            })
+            .collect::<Option<Box<_>>>()?;
+
+        Some(Self { points })
+    }
+
+    /// Returns true if the point lies within the polygon.
+    pub fn contains(&self, lat: f64, lon: f64) -> bool {
+        let mut inside = false;
+
+        let mut prev = match self.points.last() {
+            Some(point) => point,
+            None => return false,
+        };
+
+        for point in self.points.iter() {
+            let ((lat1, lon1), (lat2, lon2)) = (prev, point);
+
+            if (lat1 > &lat) != (lat2 > &lat)
+                && lon < (lon2 - lon1) * (lat - lat1) / (lat2 - lat1) + lon1
+            {
+                inside = !inside;
+            }
+
+            prev = point;
+        }
+
+        inside
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Urgency {
+    Immediate,
+    Expected,
+    Future,
+    Past,
+    Unknown,
+}
+
+impl Urgency {
+    fn parse(value: Option<&str>) -> Self {
+        match value {
+            Some("Immediate") => Urgency::Immediate,
+            Some("Expected") => Urgency::Expected,
+            Some("Future") => Urgency::Future,
+            Some("Past") => Urgency::Past,
+            _ => Urgency::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    Extreme,
+    Severe,
+    Moderate,
+    Minor,
+    Unknown,
+}
+
+impl Severity {
+    fn parse(value: Option<&str>) -> Self {
+        match value {
+            Some("Extreme") => Severity::Extreme,
+            Some("Severe") => Severity::Severe,
+            Some("Moderate") => Severity::Moderate,
+            Some("Minor") => Severity::Minor,
+            _ => Severity::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Certainty {
+    Observed,
+    Likely,
+    Possible,
+    Unlikely,
+    Unknown,
+}
+
+impl Certainty {
+    fn parse(value: Option<&str>) -> Self {
+        match value {
+            Some("Observed") => Certainty::Observed,
+            Some("Likely") => Certainty::Likely,
+            Some("Possible") => Certainty::Possible,
+            Some("Unlikely") => Certainty::Unlikely,
+            _ => Certainty::Unknown,
+        }
+    }
+}
+
+/// The color coded level of the warning used by MET Norway.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AwarenessLevel {
+    Green,
+    Yellow,
+    Orange,
+    Red,
+}
+
+impl AwarenessLevel {
+    /// The value looks like "2; yellow; Moderate".
+    fn parse(value: &str) -> Option<Self> {
+        match value.split(';').nth(1)?.trim() {
+            "green" => Some(AwarenessLevel::Green),
+            "yellow" => Some(AwarenessLevel::Yellow),
+            "orange" => Some(AwarenessLevel::Orange),
+            "red" => Some(AwarenessLevel::Red),
+            _ => None,
+        }
+    }
+}
+
+fn parse_time(value: &str) -> Result<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(value)
+        .map_err(|_| Error::Response(format!("Invalid time: {}", value).into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Alert, AwarenessLevel, Certainty, Feed, Params, Polygon, Severity, Urgency};
+    use crate::client::Request;
+
+    const CAP: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <alert xmlns="urn:oasis:names:tc:emergency:cap:1.2">
+            <identifier>2.49.0.1.578.0.20230321083437.045</identifier>
+            <sender>noreply@met.no</sender>
+            <sent>2023-03-21T09:34:37+01:00</sent>
+            <status>Actual</status>
+            <msgType>Alert</msgType>
+            <scope>Public</scope>
+            <info>
+                <language>en-GB</language>
+                <category>Met</category>
+                <event>gale</event>
+                <urgency>Future</urgency>
+                <severity>Moderate</severity>
+                <certainty>Likely</certainty>
+                <onset>2023-03-21T18:00:00+01:00</onset>
+                <expires>2023-03-22T06:00:00+01:00</expires>
+                <headline>Gale warning</headline>
+                <parameter>
+                    <valueName>awareness_level</valueName>
+                    <value>2; yellow; Moderate</value>
+                </parameter>
+                <area>
+                    <areaDesc>Oslofjord</areaDesc>
+                    <polygon>59.0,10.0 59.0,11.0 60.0,11.0 60.0,10.0 59.0,10.0</polygon>
+                </area>
+            </info>
+        </alert>"#;
+
+    #[test]
+    fn parses_feed() {
+        let feed = Feed::parse(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <rss version="2.0">
+                <channel>
+                    <title>MET Norway weather warnings</title>
+                    <item>
+                        <title>Gale warning, yellow level, Oslofjord</title>
+                        <link>https://api.met.no/weatherapi/metalerts/2.0/current?cap=2.49.0.1.578.0.20230321083437.045</link>
+                        <guid>2.49.0.1.578.0.20230321083437.045</guid>
+                        <pubDate>Tue, 21 Mar 2023 08:34:37 +0000</pubDate>
+                    </item>
+                </channel>
+            </rss>"#,
+        )
+        .unwrap();
+
+        assert_eq!(feed.items.len(), 1);
+        assert_eq!(&*feed.items[0].cap, "2.49.0.1.578.0.20230321083437.045");
+        assert!(feed.items[0].published_at.is_some());
+    }
+
+    #[test]
+    fn parses_alert() {
+        let alert = Alert::parse(CAP).unwrap();
+        assert_eq!(&*alert.msg_type, "Alert");
+
+        let info = &alert.infos[0];
+        assert_eq!(&*info.event, "gale");
+        assert_eq!(info.urgency, Urgency::Future);
+        assert_eq!(info.severity, Severity::Moderate);
+        assert_eq!(info.certainty, Certainty::Likely);
+        assert_eq!(info.awareness_level, Some(AwarenessLevel::Yellow));
+        assert_eq!(&*info.areas[0].description, "Oslofjord");
+        assert_eq!(info.areas[0].polygons[0].points.len(), 5);
+        assert!(info.onset.unwrap() < info.expires.unwrap());
+
+        assert!(alert.contains(&crate::Params::new(59.9139, 10.7522, None).unwrap()));
+        assert!(!alert.contains(&crate::Params::new(60.3913, 5.3221, None).unwrap()));
+    }
+
+    #[test]
+    fn tests_points_in_polygon() {
+        let polygon = Polygon::parse("0,0 0,4 2,2 4,4 4,0 0,0").unwrap();
+
+        assert!(polygon.contains(1.0, 1.0));
+        assert!(polygon.contains(3.0, 1.0));
+        assert!(!polygon.contains(2.0, 3.0));
+        assert!(!polygon.contains(5.0, 1.0));
+    }
+
+    #[test]
+    fn creates_query() {
+        let params = Params::county("03").unwrap();
+        assert_eq!(params.query(), [("county", "03".to_string())]);
+
+        assert!(Params::county("Oslo").is_err());
+    }
+}
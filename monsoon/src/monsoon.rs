@@ -1,22 +1,69 @@
 use chrono::{DateTime, FixedOffset};
 use tower_service::Service;
+use url::Url;
 
 use std::{
     borrow::Cow,
     future::Future,
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
 };
 
-use crate::{body::Body, client::Client, Error, Result};
+use crate::{
+    airquality,
+    body::{compact, Body},
+    cache::{CacheKey, CacheStore, MemoryCache},
+    client::{Client, Request},
+    error::outside_coverage,
+    metalerts, nowcast, ocean, sunrise, textforecast, tidalwater,
+    transport::Transport,
+    Error, RateLimit, Result, RetryPolicy,
+};
+
+const DEFAULT_BASE_URL: &str = "https://api.met.no/weatherapi/";
+
+/// The variant of the locationforecast API to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Endpoint {
+    /// All of the available values. Parse the response with [Response::body].
+    #[default]
+    Complete,
+    /// Only the most commonly used values (temperature, wind, precipitation, ...). Parse the
+    /// response with [Response::compact_body].
+    Compact,
+}
+
+impl Endpoint {
+    pub(crate) fn path(&self) -> &'static str {
+        match self {
+            Endpoint::Complete => "locationforecast/2.0/complete",
+            Endpoint::Compact => "locationforecast/2.0/compact",
+        }
+    }
+}
 
 /// The coordinates for which the weather should be looked up.
+///
+/// The "complete" API is used by default. Set [Params::endpoint] to use the smaller "compact" API
+/// instead:
+///
+/// ```no_run
+/// use monsoon::{Endpoint, Params};
+///
+/// # fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+/// let mut params = Params::new(50.0880, 14.4207, None)?;
+/// params.endpoint = Endpoint::Compact;
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub struct Params {
     pub lat: f64,
     pub lon: f64,
     pub alt: Option<i32>,
+    pub endpoint: Endpoint,
 
     pub last_response: Option<Response>,
 }
@@ -47,13 +94,7 @@ impl Params {
         alt: impl Into<Option<i32>>,
         last_response: impl Into<Option<Response>>,
     ) -> Result<Self> {
-        if !lat.is_finite() || lat.abs() > 90.0 {
-            return Err(Error::Params("Invalid lat value."));
-        }
-
-        if !lon.is_finite() || lon.abs() > 180.0 {
-            return Err(Error::Params("Invalid lon value."));
-        }
+        let (lat, lon) = normalize_coordinates(lat, lon)?;
 
         let alt = alt.into();
         if let Some(alt) = alt {
@@ -63,28 +104,66 @@ impl Params {
         }
 
         Ok(Self {
-            lat: (lat * 10000.0).trunc() / 10000.0,
-            lon: (lon * 10000.0).trunc() / 10000.0,
+            lat,
+            lon,
             alt,
+            endpoint: Endpoint::default(),
             last_response: last_response.into(),
         })
     }
 }
 
+impl Request for Params {
+    fn path(&self) -> &'static str {
+        self.endpoint.path()
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![("lat", self.lat.to_string()), ("lon", self.lon.to_string())];
+
+        if let Some(alt) = self.alt {
+            query.push(("altitude", alt.to_string()));
+        }
+
+        query
+    }
+
+    fn last_response(&self) -> Option<&Response> {
+        self.last_response.as_ref()
+    }
+}
+
+/// Validates the coordinates and truncates them to 4 fractional places.
+pub(crate) fn normalize_coordinates(lat: f64, lon: f64) -> Result<(f64, f64)> {
+    if !lat.is_finite() || lat.abs() > 90.0 {
+        return Err(Error::Params("Invalid lat value."));
+    }
+
+    if !lon.is_finite() || lon.abs() > 180.0 {
+        return Err(Error::Params("Invalid lon value."));
+    }
+
+    Ok((
+        (lat * 10000.0).trunc() / 10000.0,
+        (lon * 10000.0).trunc() / 10000.0,
+    ))
+}
+
 /// Response from the API.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct Response {
     expires_at: DateTime<FixedOffset>,
     last_modified: Box<str>,
-    pub(crate) raw_body: Box<str>,
+    pub(crate) raw_body: Arc<str>,
 }
 
 impl Response {
     pub(crate) fn new(
         expires_at: DateTime<FixedOffset>,
         last_modified: Box<str>,
-        raw_body: Box<str>,
+        raw_body: Arc<str>,
     ) -> Self {
         Self {
             expires_at,
@@ -101,15 +180,22 @@ impl Response {
         &self.last_modified
     }
 
+    /// Parses the body of a response from the "complete" API.
     pub fn body(&self) -> Result<Body<'_>> {
         serde_json::from_str::<Body>(&self.raw_body).map_err(Into::into)
     }
+
+    /// Parses the body of a response from the "compact" API (see [Endpoint::Compact]).
+    pub fn compact_body(&self) -> Result<compact::Body<'_>> {
+        serde_json::from_str::<compact::Body>(&self.raw_body).map_err(Into::into)
+    }
 }
 
 /// The main entry point of the library.
 #[derive(Debug, Clone)]
 pub struct Monsoon {
     client: Client,
+    cache: Option<Arc<dyn CacheStore>>,
 }
 
 impl Monsoon {
@@ -123,8 +209,23 @@ impl Monsoon {
     ///let monsoon = Monsoon::new("test.com support@test.com");
     ///```
     pub fn new(user_agent: impl Into<Cow<'static, str>>) -> Result<Self> {
-        let client = Client::new(user_agent.into())?;
-        Ok(Self { client })
+        Self::builder(user_agent).build()
+    }
+
+    /// Creates a new builder with the given user agent. Use it to configure the instance beyond
+    /// the defaults.
+    ///
+    /// Example:
+    ///
+    ///```no_run
+    ///use monsoon::Monsoon;
+    ///
+    ///let monsoon = Monsoon::builder("test.com support@test.com")
+    ///    .base_url("http://localhost:8080/weatherapi/")
+    ///    .build();
+    ///```
+    pub fn builder(user_agent: impl Into<Cow<'static, str>>) -> MonsoonBuilder {
+        MonsoonBuilder::new(user_agent)
     }
 
     /// Fetches weather data for the given coordinates.
@@ -165,7 +266,9 @@ impl Monsoon {
         self.get_with_params(Params::new(lat, lon, alt)?).await
     }
 
-    /// Fetches weather data for the given coordinates provided via Params.
+    /// Fetches weather data for the given coordinates provided via Params. If a cache is
+    /// configured (see [MonsoonBuilder::cache] and [MonsoonBuilder::cache_store]) and no last
+    /// response is provided, the cached response for the coordinates is used in its place.
     ///
     /// Example:
     ///
@@ -182,9 +285,316 @@ impl Monsoon {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_with_params(&self, params: Params) -> Result<Response> {
-        self.client.get(params).await
+    pub async fn get_with_params(&self, mut params: Params) -> Result<Response> {
+        let Some(cache) = &self.cache else {
+            return self.client.get(&params).await;
+        };
+
+        let key = CacheKey::from(&params);
+        let mut cached = None;
+        if params.last_response.is_none() {
+            params.last_response = cache.get(&key);
+            cached = params
+                .last_response
+                .as_ref()
+                .map(|response| (response.expires_at, response.last_modified.clone()));
+        }
+
+        let response = self.client.get(&params).await?;
+
+        // Fresh responses are returned from the cache as they are, no need to store them again
+        let unchanged = cached.is_some_and(|(expires_at, last_modified)| {
+            response.expires_at == expires_at && response.last_modified == last_modified
+        });
+        if !unchanged {
+            cache.insert(key, response.clone());
+        }
+
+        Ok(response)
+    }
+
+    /// Fetches the nowcast for the given coordinates. Parse the response with
+    /// [Response::nowcast_body]. Locations outside of the covered area (the Nordic countries)
+    /// fail with [Error::OutsideCoverage]. Check [RadarCoverage] to see whether the precipitation
+    /// data is available.
+    ///
+    /// Example:
+    ///
+    /// ```no_run
+    /// use monsoon::{nowcast, Monsoon};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    /// let params = nowcast::Params::new(59.9139, 10.7522, None)?;
+    ///
+    /// let monsoon = Monsoon::new("test.com support@test.com")?;
+    /// let response = monsoon.get_nowcast(params).await?;
+    /// let body = response.nowcast_body()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [RadarCoverage]: nowcast::RadarCoverage
+    pub async fn get_nowcast(&self, params: nowcast::Params) -> Result<Response> {
+        self.client.get(&params).await.map_err(outside_coverage)
+    }
+
+    /// Fetches the feed of the current weather warnings for the given location. Parse the response
+    /// with [Response::alert_feed] and fetch the individual warnings via [Monsoon::get_alert]. See
+    /// the [metalerts] module for an example.
+    pub async fn get_alerts(&self, params: metalerts::Params) -> Result<Response> {
+        self.client.get(&params).await
+    }
+
+    /// Fetches the details of a single weather warning, identified by [FeedItem::cap]. Parse the
+    /// response with [Response::alert].
+    ///
+    /// [FeedItem::cap]: metalerts::FeedItem::cap
+    pub async fn get_alert(&self, cap: &str) -> Result<Response> {
+        self.client.get(&metalerts::CapRequest { cap }).await
+    }
+
+    /// Fetches the times of sunrise, sunset and other events of the Sun. Parse the response with
+    /// [Response::sun_body].
+    ///
+    /// Example:
+    ///
+    /// ```no_run
+    /// use chrono::NaiveDate;
+    /// use monsoon::{sunrise, Monsoon};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    /// let date = NaiveDate::from_ymd_opt(2023, 3, 22).unwrap();
+    /// let params = sunrise::Params::new(50.0880, 14.4207, date, None)?;
+    ///
+    /// let monsoon = Monsoon::new("test.com support@test.com")?;
+    /// let response = monsoon.get_sun(params).await?;
+    /// let body = response.sun_body()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_sun(&self, params: sunrise::Params) -> Result<Response> {
+        self.client.get(&sunrise::Request::sun(params)).await
+    }
+
+    /// Fetches the times of moonrise, moonset and other events of the Moon, including its
+    /// phase. Parse the response with [Response::moon_body].
+    ///
+    /// Example:
+    ///
+    /// ```no_run
+    /// use chrono::{FixedOffset, NaiveDate};
+    /// use monsoon::{sunrise, Monsoon};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    /// let date = NaiveDate::from_ymd_opt(2023, 3, 22).unwrap();
+    /// let params = sunrise::Params::new(50.0880, 14.4207, date, FixedOffset::east_opt(3600))?;
+    ///
+    /// let monsoon = Monsoon::new("test.com support@test.com")?;
+    /// let response = monsoon.get_moon(params).await?;
+    /// let phase = response.moon_body()?.properties.phase();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_moon(&self, params: sunrise::Params) -> Result<Response> {
+        self.client.get(&sunrise::Request::moon(params)).await
+    }
+
+    /// Fetches the forecast of the marine conditions (waves, sea temperature and currents) for the
+    /// given coordinates. Parse the response with [Response::ocean_body]. Locations on land or
+    /// outside of the area covered by the ocean model fail with [Error::OutsideCoverage].
+    ///
+    /// Example:
+    ///
+    /// ```no_run
+    /// use monsoon::{ocean, Monsoon};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    /// let params = ocean::Params::new(60.10, 5.0)?;
+    ///
+    /// let monsoon = Monsoon::new("test.com support@test.com")?;
+    /// let response = monsoon.get_ocean_forecast(params).await?;
+    /// let body = response.ocean_body()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_ocean_forecast(&self, params: ocean::Params) -> Result<Response> {
+        self.client.get(&params).await.map_err(outside_coverage)
+    }
+
+    /// Fetches the air quality forecast for the given location. Parse the response with
+    /// [Response::air_quality_body]. See the [airquality] module for an example.
+    pub async fn get_air_quality(&self, params: airquality::Params) -> Result<Response> {
+        self.client.get(&params).await
+    }
+
+    /// Fetches the list of areas of the given class for which the air quality forecast is
+    /// available. Parse the response with [Response::air_quality_areas].
+    pub async fn get_air_quality_areas(&self, class: airquality::AreaClass) -> Result<Response> {
+        self.client.get(&airquality::AreasRequest { class }).await
+    }
+
+    /// Fetches the list of measuring stations for which the air quality forecast is available.
+    /// Parse the response with [Response::air_quality_stations].
+    pub async fn get_air_quality_stations(&self) -> Result<Response> {
+        self.client.get(&airquality::StationsRequest).await
+    }
+
+    /// Fetches the given text forecast. Parse the response with [Response::text_forecast]. See
+    /// the [textforecast] module for an example.
+    pub async fn get_text_forecast(&self, params: textforecast::Params) -> Result<Response> {
+        self.client.get(&params).await
     }
+
+    /// Fetches the list of the available text forecasts. Parse the response with
+    /// [Response::text_forecasts].
+    pub async fn get_text_forecasts(&self) -> Result<Response> {
+        self.client.get(&textforecast::AvailableRequest).await
+    }
+
+    /// Fetches the observed and predicted water levels for the given harbor. Parse the response
+    /// with [Response::tidal_water]. See the [tidalwater] module for an example.
+    pub async fn get_tidal_water(&self, params: tidalwater::Params) -> Result<Response> {
+        self.client.get(&params).await
+    }
+}
+
+/// Builder for [Monsoon] instances. Created via [Monsoon::builder].
+#[derive(Debug, Clone)]
+pub struct MonsoonBuilder {
+    user_agent: Cow<'static, str>,
+    base_url: Cow<'static, str>,
+    cache: Option<Arc<dyn CacheStore>>,
+    retry: Option<RetryPolicy>,
+    rate_limit: Option<RateLimit>,
+    transport: Option<Arc<dyn Transport>>,
+}
+
+impl MonsoonBuilder {
+    fn new(user_agent: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            user_agent: user_agent.into(),
+            base_url: DEFAULT_BASE_URL.into(),
+            cache: None,
+            retry: None,
+            rate_limit: Some(RateLimit::default()),
+            transport: None,
+        }
+    }
+
+    /// Sets the base URL against which the individual APIs are resolved. Defaults to
+    /// `https://api.met.no/weatherapi/`. Useful for pointing the client at a mirror or a local
+    /// stand-in.
+    pub fn base_url(mut self, base_url: impl Into<Cow<'static, str>>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Enables an in-memory cache of responses keyed by the coordinates (lat, lon, alt). Cached
+    /// responses are returned as they are until they expire, after which they're revalidated
+    /// using the "If-Modified-Since" header. At most `capacity` responses are kept and the least
+    /// recently used one is evicted first. The cache is shared by all clones of the instance.
+    pub fn cache(self, capacity: usize) -> Self {
+        self.cache_store(MemoryCache::new(capacity))
+    }
+
+    /// Uses the given store to cache responses. It works the same way as [MonsoonBuilder::cache]
+    /// but allows for custom storage, such as [FileCache] which keeps the responses across
+    /// process restarts.
+    ///
+    /// [FileCache]: crate::cache::FileCache
+    pub fn cache_store(mut self, store: impl CacheStore + 'static) -> Self {
+        self.cache = Some(Arc::new(store));
+        self
+    }
+
+    /// Enables retrying of requests which failed because of a transient error, such as a
+    /// connection reset or HTTP 503. Disabled by default. Waiting between the attempts requires
+    /// the Tokio runtime with the time driver enabled.
+    pub fn retry(mut self, retry: impl Into<Option<RetryPolicy>>) -> Self {
+        self.retry = retry.into();
+        self
+    }
+
+    /// Sets the limits on the rate and concurrency of requests. Defaults to [RateLimit::default]
+    /// which follows the Terms of Service. The limits are shared by all clones of the instance
+    /// (but not by separately built instances). Pass `None` to disable the limits, e.g. when
+    /// they're enforced by other means. Waiting for the limits requires the Tokio runtime with the
+    /// time driver enabled.
+    pub fn rate_limit(mut self, rate_limit: impl Into<Option<RateLimit>>) -> Self {
+        self.rate_limit = rate_limit.into();
+        self
+    }
+
+    /// Sets the transport used to send the requests, e.g. to reuse an existing HTTP stack or to
+    /// serve the responses from memory in tests. Defaults to [ReqwestTransport] if the `reqwest`
+    /// feature is enabled (the default). Otherwise, it has to be set.
+    ///
+    /// [ReqwestTransport]: crate::transport::ReqwestTransport
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Creates the configured [Monsoon] instance.
+    pub fn build(self) -> Result<Monsoon> {
+        let transport = match self.transport {
+            Some(transport) => transport,
+            None => default_transport()?,
+        };
+
+        let client = Client::new(
+            transport,
+            self.user_agent,
+            parse_base_url(&self.base_url)?,
+            self.retry,
+            self.rate_limit,
+        )?;
+        Ok(Monsoon {
+            client,
+            cache: self.cache,
+        })
+    }
+
+    /// Creates the configured [blocking::Monsoon] instance. Requires the `blocking` feature.
+    ///
+    /// [blocking::Monsoon]: crate::blocking::Monsoon
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(self) -> Result<crate::blocking::Monsoon> {
+        crate::blocking::Monsoon::from_async(self.build()?)
+    }
+}
+
+#[cfg(feature = "reqwest")]
+fn default_transport() -> Result<Arc<dyn Transport>> {
+    let transport = crate::transport::ReqwestTransport::new().map_err(Error::HttpClient)?;
+    Ok(Arc::new(transport))
+}
+
+#[cfg(not(feature = "reqwest"))]
+fn default_transport() -> Result<Arc<dyn Transport>> {
+    Err(Error::Params(
+        "Missing transport value. Set one via MonsoonBuilder::transport.",
+    ))
+}
+
+fn parse_base_url(base_url: &str) -> Result<Url> {
+    let mut url = Url::parse(base_url).map_err(|_| Error::Params("Invalid base URL value."))?;
+
+    if url.cannot_be_a_base() {
+        return Err(Error::Params("Invalid base URL value."));
+    }
+
+    // Make sure the last path segment isn't replaced when joining the individual APIs.
+    if !url.path().ends_with('/') {
+        let path = format!("{}/", url.path());
+        url.set_path(&path);
+    }
+
+    Ok(url)
 }
 
 impl Service<Params> for Monsoon {
@@ -247,4 +657,85 @@ mod tests {
             assert_eq!(params.lon, 12.7654);
         }
     }
+
+    mod builder {
+        use crate::monsoon::parse_base_url;
+
+        #[test]
+        fn validates_base_url() {
+            for url in ["", "localhost", "mailto:support@test.com"] {
+                assert!(parse_base_url(url).is_err());
+            }
+        }
+
+        #[test]
+        fn appends_trailing_slash_to_base_url() {
+            assert_eq!(
+                parse_base_url("http://localhost:8080/weatherapi")
+                    .unwrap()
+                    .as_str(),
+                "http://localhost:8080/weatherapi/"
+            );
+            assert_eq!(
+                parse_base_url("http://localhost:8080").unwrap().as_str(),
+                "http://localhost:8080/"
+            );
+        }
+    }
+
+    mod cache {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        };
+
+        use crate::{
+            cache::{CacheKey, CacheStore},
+            transport::{
+                fake::{headers, FakeTransport},
+                StatusCode,
+            },
+            Monsoon, Response,
+        };
+
+        #[derive(Debug, Default)]
+        struct CountingCache {
+            response: Mutex<Option<Response>>,
+            inserts: Arc<AtomicUsize>,
+        }
+
+        impl CacheStore for CountingCache {
+            fn get(&self, _key: &CacheKey) -> Option<Response> {
+                self.response.lock().unwrap().clone()
+            }
+
+            fn insert(&self, _key: CacheKey, response: Response) {
+                self.inserts.fetch_add(1, Ordering::Relaxed);
+                *self.response.lock().unwrap() = Some(response);
+            }
+        }
+
+        #[tokio::test]
+        async fn skips_insert_of_fresh_cached_response() {
+            let cache = CountingCache::default();
+            let inserts = cache.inserts.clone();
+            let transport = FakeTransport::default().respond(
+                StatusCode::OK,
+                headers("Fri, 01 Jan 2100 00:00:00 GMT"),
+                "{}",
+            );
+            let monsoon = Monsoon::builder("test.com support@test.com")
+                .transport(transport.clone())
+                .cache_store(cache)
+                .build()
+                .unwrap();
+
+            for _ in 0..3 {
+                monsoon.get(50.0880, 14.4207).await.unwrap();
+            }
+
+            assert_eq!(transport.calls(), 1);
+            assert_eq!(inserts.load(Ordering::Relaxed), 1);
+        }
+    }
 }
@@ -0,0 +1,272 @@
+//! Short-term forecast of precipitation based on radar data as provided by the [Nowcast API].
+//! It's available only for the Nordic countries, use [Monsoon::get_nowcast] to fetch it.
+//!
+//! [Nowcast API]: https://api.met.no/weatherapi/nowcast/2.0/documentation
+//! [Monsoon::get_nowcast]: crate::Monsoon::get_nowcast
+use std::borrow::Cow;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{
+    body::{Geometry, Summary, Unit},
+    client::Request,
+    Response, Result,
+};
+
+/// The coordinates for which the nowcast should be looked up.
+///
+/// Example:
+///
+/// ```no_run
+/// use monsoon::nowcast::Params;
+///
+/// # fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+/// let params = Params::new(59.9139, 10.7522, None)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Params {
+    pub lat: f64,
+    pub lon: f64,
+    pub alt: Option<i32>,
+
+    pub last_response: Option<Response>,
+}
+
+impl Params {
+    /// Creates a new Params instance. The parameters are validated and normalized the same way as
+    /// in [crate::Params::new].
+    pub fn new(lat: f64, lon: f64, alt: impl Into<Option<i32>>) -> Result<Self> {
+        Self::new_with_last_response(lat, lon, alt, None)
+    }
+
+    pub fn new_with_last_response(
+        lat: f64,
+        lon: f64,
+        alt: impl Into<Option<i32>>,
+        last_response: impl Into<Option<Response>>,
+    ) -> Result<Self> {
+        let crate::Params { lat, lon, alt, .. } = crate::Params::new(lat, lon, alt)?;
+
+        Ok(Self {
+            lat,
+            lon,
+            alt,
+            last_response: last_response.into(),
+        })
+    }
+}
+
+impl Request for Params {
+    fn path(&self) -> &'static str {
+        "nowcast/2.0/complete"
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![("lat", self.lat.to_string()), ("lon", self.lon.to_string())];
+
+        if let Some(alt) = self.alt {
+            query.push(("altitude", alt.to_string()));
+        }
+
+        query
+    }
+
+    fn last_response(&self) -> Option<&Response> {
+        self.last_response.as_ref()
+    }
+}
+
+impl Response {
+    /// Parses the body of a response from [Monsoon::get_nowcast].
+    ///
+    /// [Monsoon::get_nowcast]: crate::Monsoon::get_nowcast
+    pub fn nowcast_body(&self) -> Result<Body<'_>> {
+        serde_json::from_str::<Body>(&self.raw_body).map_err(Into::into)
+    }
+}
+
+/// Response body of the Nowcast API as defined in the [`documentation`].
+///
+/// [`documentation`]: https://api.met.no/weatherapi/nowcast/2.0/documentation
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Body<'a> {
+    #[serde(rename(deserialize = "type"), borrow)]
+    pub type_field: Cow<'a, str>,
+    pub geometry: Geometry<'a>,
+    pub properties: Properties,
+}
+
+impl Body<'_> {
+    /// Converts the body into one which doesn't borrow from the [Response] so that it can be
+    /// stored or sent elsewhere independently of it.
+    pub fn into_owned(self) -> Body<'static> {
+        Body {
+            type_field: Cow::Owned(self.type_field.into_owned()),
+            geometry: self.geometry.into_owned(),
+            properties: self.properties,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Properties {
+    pub meta: Meta,
+    pub timeseries: Box<[TimeSeries]>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Meta {
+    pub updated_at: DateTime<Utc>,
+    pub units: Units,
+    pub radar_coverage: RadarCoverage,
+}
+
+/// Availability of the radar data the precipitation is based on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum RadarCoverage {
+    #[serde(rename = "ok")]
+    Ok,
+    /// The radar data is temporarily missing and the precipitation isn't reliable.
+    #[serde(rename = "temporarily unavailable")]
+    TemporarilyUnavailable,
+    /// The location isn't covered by the radars and the precipitation isn't available.
+    #[serde(rename = "no coverage")]
+    NoCoverage,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Units {
+    pub air_temperature: Option<Unit>,
+    pub precipitation_amount: Option<Unit>,
+    pub precipitation_rate: Option<Unit>,
+    pub relative_humidity: Option<Unit>,
+    pub wind_from_direction: Option<Unit>,
+    pub wind_speed: Option<Unit>,
+    pub wind_speed_of_gust: Option<Unit>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TimeSeries {
+    pub time: DateTime<Utc>,
+    pub data: Data,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Data {
+    pub instant: Instant,
+    pub next_1_hours: Option<NextHours>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Instant {
+    pub details: InstantDetails,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct InstantDetails {
+    pub air_temperature: Option<f64>,
+    pub precipitation_rate: Option<f64>,
+    pub relative_humidity: Option<f64>,
+    pub wind_from_direction: Option<f64>,
+    pub wind_speed: Option<f64>,
+    pub wind_speed_of_gust: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct NextHours {
+    pub details: Option<SummaryDetails>,
+    pub summary: Summary,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SummaryDetails {
+    pub precipitation_amount: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+
+    use super::{Body, Params, RadarCoverage};
+    use crate::{client::Request, error::outside_coverage, Error, HttpError};
+
+    #[test]
+    fn creates_query() {
+        let params = Params::new(59.91391, 10.7522, 10).unwrap();
+
+        assert_eq!(params.path(), "nowcast/2.0/complete");
+        assert_eq!(
+            params.query(),
+            [
+                ("lat", "59.9139".to_string()),
+                ("lon", "10.7522".to_string()),
+                ("altitude", "10".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_body() {
+        let body: Body = serde_json::from_str(
+            r#"{
+                "type": "Feature",
+                "geometry": { "type": "Point", "coordinates": [10.7522, 59.9139, 10] },
+                "properties": {
+                    "meta": {
+                        "updated_at": "2023-03-21T09:05:00Z",
+                        "units": { "precipitation_rate": "mm/h" },
+                        "radar_coverage": "temporarily unavailable"
+                    },
+                    "timeseries": [{
+                        "time": "2023-03-21T09:05:00Z",
+                        "data": {
+                            "instant": { "details": { "precipitation_rate": 0.3 } },
+                            "next_1_hours": {
+                                "summary": { "symbol_code": "lightrain" },
+                                "details": { "precipitation_amount": 0.4 }
+                            }
+                        }
+                    }]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let properties = &body.properties;
+        assert_eq!(
+            properties.meta.radar_coverage,
+            RadarCoverage::TemporarilyUnavailable
+        );
+        assert_eq!(
+            properties.timeseries[0]
+                .data
+                .instant
+                .details
+                .precipitation_rate,
+            Some(0.3)
+        );
+    }
+
+    #[test]
+    fn reports_locations_outside_coverage() {
+        let err = outside_coverage(Error::Client(HttpError::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            None,
+            None,
+        )));
+        assert!(matches!(err, Error::OutsideCoverage(_)));
+
+        let err = outside_coverage(Error::Client(HttpError::new(
+            StatusCode::BAD_REQUEST,
+            None,
+            None,
+        )));
+        assert!(matches!(err, Error::Client(_)));
+    }
+}
@@ -0,0 +1,274 @@
+//! Forecast of the marine conditions (waves, sea temperature and currents) as provided by the
+//! [Oceanforecast API]. It's available only for the seas around Norway, use
+//! [Monsoon::get_ocean_forecast] to fetch it.
+//!
+//! [Oceanforecast API]: https://api.met.no/weatherapi/oceanforecast/2.0/documentation
+//! [Monsoon::get_ocean_forecast]: crate::Monsoon::get_ocean_forecast
+use std::borrow::Cow;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{
+    body::{with_unit, Length, Speed, Temperature, Unit},
+    client::Request,
+    monsoon::normalize_coordinates,
+    Response, Result,
+};
+
+/// The coordinates for which the forecast should be looked up.
+///
+/// Example:
+///
+/// ```no_run
+/// use monsoon::ocean::Params;
+///
+/// # fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+/// let params = Params::new(60.10, 5.0)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Params {
+    pub lat: f64,
+    pub lon: f64,
+
+    pub last_response: Option<Response>,
+}
+
+impl Params {
+    /// Creates a new Params instance. The coordinates are validated and normalized the same way
+    /// as in [crate::Params::new].
+    pub fn new(lat: f64, lon: f64) -> Result<Self> {
+        Self::new_with_last_response(lat, lon, None)
+    }
+
+    pub fn new_with_last_response(
+        lat: f64,
+        lon: f64,
+        last_response: impl Into<Option<Response>>,
+    ) -> Result<Self> {
+        let (lat, lon) = normalize_coordinates(lat, lon)?;
+
+        Ok(Self {
+            lat,
+            lon,
+            last_response: last_response.into(),
+        })
+    }
+}
+
+impl Request for Params {
+    fn path(&self) -> &'static str {
+        "oceanforecast/2.0/complete"
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        vec![("lat", self.lat.to_string()), ("lon", self.lon.to_string())]
+    }
+
+    fn last_response(&self) -> Option<&Response> {
+        self.last_response.as_ref()
+    }
+}
+
+impl Response {
+    /// Parses the body of a response from [Monsoon::get_ocean_forecast].
+    ///
+    /// [Monsoon::get_ocean_forecast]: crate::Monsoon::get_ocean_forecast
+    pub fn ocean_body(&self) -> Result<Body<'_>> {
+        serde_json::from_str::<Body>(&self.raw_body).map_err(Into::into)
+    }
+}
+
+/// Response body of the Oceanforecast API as defined in the [`documentation`].
+///
+/// [`documentation`]: https://api.met.no/weatherapi/oceanforecast/2.0/documentation
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Body<'a> {
+    #[serde(rename(deserialize = "type"), borrow)]
+    pub type_field: Cow<'a, str>,
+    pub geometry: Geometry<'a>,
+    pub properties: Properties,
+}
+
+impl Body<'_> {
+    /// Converts the body into one which doesn't borrow from the [Response] so that it can be
+    /// stored or sent elsewhere independently of it.
+    pub fn into_owned(self) -> Body<'static> {
+        Body {
+            type_field: Cow::Owned(self.type_field.into_owned()),
+            geometry: self.geometry.into_owned(),
+            properties: self.properties,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Geometry<'a> {
+    #[serde(rename(deserialize = "type"), borrow)]
+    pub type_field: Cow<'a, str>,
+    pub coordinates: Coordinates,
+}
+
+impl Geometry<'_> {
+    pub fn into_owned(self) -> Geometry<'static> {
+        Geometry {
+            type_field: Cow::Owned(self.type_field.into_owned()),
+            coordinates: self.coordinates,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Coordinates {
+    pub longitude: f64,
+    pub latitude: f64,
+    // The ocean forecast doesn't always include the altitude.
+    #[serde(default)]
+    pub altitude: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Properties {
+    pub meta: Meta,
+    pub timeseries: Box<[TimeSeries]>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Meta {
+    pub updated_at: DateTime<Utc>,
+    pub units: Units,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Units {
+    pub sea_surface_wave_from_direction: Option<Unit>,
+    pub sea_surface_wave_height: Option<Unit>,
+    pub sea_water_speed: Option<Unit>,
+    pub sea_water_temperature: Option<Unit>,
+    pub sea_water_to_direction: Option<Unit>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TimeSeries {
+    pub time: DateTime<Utc>,
+    pub data: Data,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Data {
+    pub instant: Instant,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Instant {
+    pub details: InstantDetails,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct InstantDetails {
+    pub sea_surface_wave_from_direction: Option<f64>,
+    pub sea_surface_wave_height: Option<f64>,
+    pub sea_water_speed: Option<f64>,
+    pub sea_water_temperature: Option<f64>,
+    pub sea_water_to_direction: Option<f64>,
+}
+
+impl InstantDetails {
+    pub fn sea_surface_wave_height_with_unit(&self, units: &Units) -> Option<Length> {
+        with_unit(
+            self.sea_surface_wave_height,
+            &units.sea_surface_wave_height,
+            Unit::length,
+            Length::new,
+        )
+    }
+
+    pub fn sea_water_speed_with_unit(&self, units: &Units) -> Option<Speed> {
+        with_unit(
+            self.sea_water_speed,
+            &units.sea_water_speed,
+            Unit::speed,
+            Speed::new,
+        )
+    }
+
+    pub fn sea_water_temperature_with_unit(&self, units: &Units) -> Option<Temperature> {
+        with_unit(
+            self.sea_water_temperature,
+            &units.sea_water_temperature,
+            Unit::temperature,
+            Temperature::new,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Body, Params};
+    use crate::{
+        body::{Length, LengthUnit},
+        client::Request,
+    };
+
+    #[test]
+    fn creates_query() {
+        let params = Params::new(60.10, 5.0).unwrap();
+
+        assert_eq!(params.path(), "oceanforecast/2.0/complete");
+        assert_eq!(
+            params.query(),
+            [("lat", "60.1".to_string()), ("lon", "5".to_string())]
+        );
+    }
+
+    #[test]
+    fn parses_body() {
+        let body: Body = serde_json::from_str(
+            r#"{
+                "type": "Feature",
+                "geometry": { "type": "Point", "coordinates": [5.0, 60.1] },
+                "properties": {
+                    "meta": {
+                        "updated_at": "2023-03-21T09:13:45Z",
+                        "units": {
+                            "sea_surface_wave_from_direction": "degrees",
+                            "sea_surface_wave_height": "m",
+                            "sea_water_speed": "m/s",
+                            "sea_water_temperature": "celsius",
+                            "sea_water_to_direction": "degrees"
+                        }
+                    },
+                    "timeseries": [{
+                        "time": "2023-03-21T09:00:00Z",
+                        "data": {
+                            "instant": {
+                                "details": {
+                                    "sea_surface_wave_from_direction": 193.4,
+                                    "sea_surface_wave_height": 1.6,
+                                    "sea_water_speed": 0.3,
+                                    "sea_water_temperature": 6.2,
+                                    "sea_water_to_direction": 12.5
+                                }
+                            }
+                        }
+                    }]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let properties = &body.properties;
+        assert_eq!(body.geometry.coordinates.altitude, None);
+        assert_eq!(
+            properties.timeseries[0]
+                .data
+                .instant
+                .details
+                .sea_surface_wave_height_with_unit(&properties.meta.units),
+            Some(Length::new(1.6, LengthUnit::Meters))
+        );
+    }
+}
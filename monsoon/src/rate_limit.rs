@@ -0,0 +1,126 @@
+use std::{sync::Mutex, time::Duration};
+
+use tokio::{
+    sync::{Semaphore, SemaphorePermit},
+    time::{self, Instant},
+};
+
+/// Limits on the rate and concurrency of requests. The default enforces the 20 requests per
+/// second required by the [Terms of Service] and allows at most 50 requests in-flight at the same
+/// time.
+///
+/// Example:
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use monsoon::{Monsoon, RateLimit};
+///
+/// let monsoon = Monsoon::builder("test.com support@test.com")
+///     .rate_limit(RateLimit::new(10, Duration::from_secs(1)).concurrency(5))
+///     .build();
+/// ```
+///
+/// [Terms of Service]: https://api.met.no/doc/TermsOfService
+#[derive(Debug, Clone)]
+pub struct RateLimit {
+    requests: u32,
+    per: Duration,
+    concurrency: usize,
+}
+
+impl RateLimit {
+    /// Creates a new limit allowing at most `requests` requests per the given duration.
+    pub fn new(requests: u32, per: Duration) -> Self {
+        Self {
+            requests: requests.max(1),
+            per,
+            concurrency: 50,
+        }
+    }
+
+    /// Sets the maximum number of requests in-flight at the same time.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self::new(20, Duration::from_secs(1))
+    }
+}
+
+/// Enforces [RateLimit] by spacing the requests evenly.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    interval: Duration,
+    next_slot: Mutex<Option<Instant>>,
+    semaphore: Semaphore,
+}
+
+impl RateLimiter {
+    pub fn new(limit: &RateLimit) -> Self {
+        Self {
+            interval: limit.per / limit.requests,
+            next_slot: Mutex::new(None),
+            semaphore: Semaphore::new(limit.concurrency),
+        }
+    }
+
+    /// Waits until the next request may be sent. The request counts towards the concurrency limit
+    /// until the returned permit is dropped.
+    pub async fn acquire(&self) -> SemaphorePermit<'_> {
+        let permit = self
+            .semaphore
+            .acquire()
+            .await
+            .expect("the semaphore is never closed");
+
+        let slot = {
+            let mut next_slot = self.next_slot.lock().expect("poisoned rate limit lock");
+
+            let now = Instant::now();
+            let slot = next_slot.map_or(now, |next_slot| next_slot.max(now));
+            *next_slot = Some(slot + self.interval);
+
+            slot
+        };
+
+        time::sleep_until(slot).await;
+
+        permit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::Instant;
+
+    use super::{RateLimit, RateLimiter};
+
+    #[tokio::test(start_paused = true)]
+    async fn spaces_requests_evenly() {
+        let limiter = RateLimiter::new(&RateLimit::new(4, Duration::from_secs(1)));
+
+        let start = Instant::now();
+        for _ in 0..5 {
+            drop(limiter.acquire().await);
+        }
+
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn limits_concurrency() {
+        let limiter = RateLimiter::new(&RateLimit::new(100, Duration::from_secs(1)).concurrency(1));
+
+        let permit = limiter.acquire().await;
+        assert!(limiter.semaphore.try_acquire().is_err());
+
+        drop(permit);
+        assert!(limiter.semaphore.try_acquire().is_ok());
+    }
+}
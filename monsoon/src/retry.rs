@@ -0,0 +1,174 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use crate::Error;
+
+/// Policy for retrying requests which failed because of a transient error (see
+/// [Error::is_retryable]). The delay between attempts grows exponentially unless the server asks
+/// for a specific one via the "Retry-After" header. If the server asks for a longer delay than
+/// the [max backoff](Self::max_backoff), the request isn't retried and the error is returned.
+///
+/// Example:
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use monsoon::{Monsoon, RetryPolicy};
+///
+/// let monsoon = Monsoon::builder("test.com support@test.com")
+///     .retry(RetryPolicy::new(3).initial_backoff(Duration::from_secs(1)))
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Creates a new policy making at most `max_attempts` attempts in total (including the first
+    /// one). By default, the backoff starts at 500 ms, is capped at 30 s and has jitter applied.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+
+    /// Sets the delay after the first failed attempt. It doubles with every next attempt.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the upper bound of the backoff. If the server requests a longer delay via the
+    /// "Retry-After" header, the error is returned instead of retrying. A retry is never attempted
+    /// sooner than the server asked for.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Enables or disables jitter. With jitter, the delay is randomly picked from the upper half
+    /// of the computed backoff so that clients failing at the same time don't retry in lockstep.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Returns how long to wait before the next attempt or `None` if the request shouldn't be
+    /// retried. `attempt` is the number of the attempt which just failed, starting from 1.
+    pub(crate) fn backoff(&self, attempt: u32, err: &Error) -> Option<Duration> {
+        if attempt >= self.max_attempts || !err.is_retryable() {
+            return None;
+        }
+
+        if let Some(retry_after) = err.retry_after() {
+            return (retry_after <= self.max_backoff).then_some(retry_after);
+        }
+
+        let backoff = self
+            .initial_backoff
+            .checked_mul(2u32.saturating_pow(attempt - 1))
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+
+        if self.jitter {
+            let half = backoff / 2;
+            Some(half + half.mul_f64(random_fraction()))
+        } else {
+            Some(backoff)
+        }
+    }
+}
+
+/// Returns a pseudo-random number in [0, 1). Good enough for jitter and avoids pulling in a
+/// dependency.
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io, time::Duration};
+
+    use http::StatusCode;
+
+    use super::RetryPolicy;
+    use crate::{Error, HttpError};
+
+    fn server_error(retry_after: Option<Duration>) -> Error {
+        Error::Server(HttpError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            retry_after,
+            None,
+        ))
+    }
+
+    #[test]
+    fn backs_off_exponentially() {
+        let policy = RetryPolicy::new(5)
+            .initial_backoff(Duration::from_secs(1))
+            .max_backoff(Duration::from_secs(3))
+            .jitter(false);
+
+        let err = server_error(None);
+        assert_eq!(policy.backoff(1, &err), Some(Duration::from_secs(1)));
+        assert_eq!(policy.backoff(2, &err), Some(Duration::from_secs(2)));
+        assert_eq!(policy.backoff(3, &err), Some(Duration::from_secs(3)));
+        assert_eq!(policy.backoff(4, &err), Some(Duration::from_secs(3)));
+        assert_eq!(policy.backoff(5, &err), None);
+    }
+
+    #[test]
+    fn applies_jitter() {
+        let policy = RetryPolicy::new(5).initial_backoff(Duration::from_secs(2));
+
+        for _ in 0..100 {
+            let backoff = policy.backoff(1, &server_error(None)).unwrap();
+            assert!(backoff >= Duration::from_secs(1) && backoff <= Duration::from_secs(2));
+        }
+    }
+
+    #[test]
+    fn honors_retry_after() {
+        let policy = RetryPolicy::new(2).max_backoff(Duration::from_secs(60));
+
+        assert_eq!(
+            policy.backoff(1, &server_error(Some(Duration::from_secs(42)))),
+            Some(Duration::from_secs(42))
+        );
+
+        // Gives up rather than retrying sooner than asked
+        assert_eq!(
+            policy.backoff(1, &server_error(Some(Duration::from_secs(3600)))),
+            None
+        );
+    }
+
+    #[test]
+    fn does_not_retry_permanent_errors() {
+        let policy = RetryPolicy::new(2);
+
+        let err = Error::Client(HttpError::new(StatusCode::BAD_REQUEST, None, None));
+        assert_eq!(policy.backoff(1, &err), None);
+    }
+
+    #[test]
+    fn retries_transient_transport_errors() {
+        let policy = RetryPolicy::new(2).jitter(false);
+
+        let err = Error::HttpClient(Box::new(io::Error::from(io::ErrorKind::ConnectionReset)));
+        assert!(policy.backoff(1, &err).is_some());
+
+        let err = Error::HttpClient("invalid URL".into());
+        assert_eq!(policy.backoff(1, &err), None);
+    }
+}
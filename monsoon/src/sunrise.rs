@@ -0,0 +1,423 @@
+//! Times of sunrise, sunset and other events of the Sun and the Moon as provided by the
+//! [Sunrise API]. Use [Monsoon::get_sun] and [Monsoon::get_moon] to fetch them.
+//!
+//! [Sunrise API]: https://api.met.no/weatherapi/sunrise/3.0/documentation
+//! [Monsoon::get_sun]: crate::Monsoon::get_sun
+//! [Monsoon::get_moon]: crate::Monsoon::get_moon
+use std::{borrow::Cow, fmt};
+
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use serde::{Deserialize, Deserializer};
+
+use crate::{client, monsoon::normalize_coordinates, Response, Result};
+
+/// The location and date for which the events should be looked up.
+///
+/// Example:
+///
+/// ```no_run
+/// use chrono::{FixedOffset, NaiveDate};
+/// use monsoon::sunrise::Params;
+///
+/// # fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+/// let date = NaiveDate::from_ymd_opt(2023, 3, 22).unwrap();
+/// let params = Params::new(50.0880, 14.4207, date, FixedOffset::east_opt(3600))?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Params {
+    pub lat: f64,
+    pub lon: f64,
+    pub date: NaiveDate,
+    /// Offset from UTC of the returned times. Defaults to UTC.
+    pub offset: Option<FixedOffset>,
+
+    pub last_response: Option<Response>,
+}
+
+impl Params {
+    /// Creates a new Params instance. The coordinates are validated and normalized the same way
+    /// as in [crate::Params::new].
+    pub fn new(
+        lat: f64,
+        lon: f64,
+        date: NaiveDate,
+        offset: impl Into<Option<FixedOffset>>,
+    ) -> Result<Self> {
+        Self::new_with_last_response(lat, lon, date, offset, None)
+    }
+
+    pub fn new_with_last_response(
+        lat: f64,
+        lon: f64,
+        date: NaiveDate,
+        offset: impl Into<Option<FixedOffset>>,
+        last_response: impl Into<Option<Response>>,
+    ) -> Result<Self> {
+        let (lat, lon) = normalize_coordinates(lat, lon)?;
+
+        Ok(Self {
+            lat,
+            lon,
+            date,
+            offset: offset.into(),
+            last_response: last_response.into(),
+        })
+    }
+}
+
+/// Request for the events of one of the bodies.
+#[derive(Debug)]
+pub(crate) struct Request {
+    path: &'static str,
+    params: Params,
+}
+
+impl Request {
+    pub fn sun(params: Params) -> Self {
+        Self {
+            path: "sunrise/3.0/sun",
+            params,
+        }
+    }
+
+    pub fn moon(params: Params) -> Self {
+        Self {
+            path: "sunrise/3.0/moon",
+            params,
+        }
+    }
+}
+
+impl client::Request for Request {
+    fn path(&self) -> &'static str {
+        self.path
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let params = &self.params;
+
+        let mut query = vec![
+            ("lat", params.lat.to_string()),
+            ("lon", params.lon.to_string()),
+            ("date", params.date.format("%Y-%m-%d").to_string()),
+        ];
+
+        if let Some(offset) = params.offset {
+            query.push(("offset", offset.to_string()));
+        }
+
+        query
+    }
+
+    fn last_response(&self) -> Option<&Response> {
+        self.params.last_response.as_ref()
+    }
+}
+
+impl Response {
+    /// Parses the body of a response from [Monsoon::get_sun].
+    ///
+    /// [Monsoon::get_sun]: crate::Monsoon::get_sun
+    pub fn sun_body(&self) -> Result<SunBody> {
+        serde_json::from_str::<SunBody>(&self.raw_body).map_err(Into::into)
+    }
+
+    /// Parses the body of a response from [Monsoon::get_moon].
+    ///
+    /// [Monsoon::get_moon]: crate::Monsoon::get_moon
+    pub fn moon_body(&self) -> Result<MoonBody> {
+        serde_json::from_str::<MoonBody>(&self.raw_body).map_err(Into::into)
+    }
+}
+
+/// Response body of the Sun API as defined in the [`documentation`].
+///
+/// [`documentation`]: https://api.met.no/weatherapi/sunrise/3.0/documentation
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SunBody {
+    pub geometry: Geometry,
+    pub when: When,
+    pub properties: SunProperties,
+}
+
+/// Response body of the Moon API as defined in the [`documentation`].
+///
+/// [`documentation`]: https://api.met.no/weatherapi/sunrise/3.0/documentation
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MoonBody {
+    pub geometry: Geometry,
+    pub when: When,
+    pub properties: MoonProperties,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Geometry {
+    pub coordinates: Coordinates,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Coordinates {
+    pub longitude: f64,
+    pub latitude: f64,
+}
+
+/// The time interval the events were searched in.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct When {
+    #[serde(deserialize_with = "deserialize_interval")]
+    pub interval: [DateTime<FixedOffset>; 2],
+}
+
+/// The events of the Sun. The events which don't occur on the given date, such as the sunrise
+/// during a polar night, are `None`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SunProperties {
+    pub sunrise: Option<Event>,
+    pub sunset: Option<Event>,
+    pub solarnoon: Option<Culmination>,
+    pub solarmidnight: Option<Culmination>,
+}
+
+/// The events of the Moon. The events which don't occur on the given date are `None`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MoonProperties {
+    pub moonrise: Option<Event>,
+    pub moonset: Option<Event>,
+    pub high_moon: Option<Culmination>,
+    pub low_moon: Option<Culmination>,
+    /// Phase of the Moon in degrees, where 0 is the new moon, 90 the first quarter, 180 the full
+    /// moon and 270 the last quarter.
+    pub moonphase: Option<f64>,
+}
+
+impl MoonProperties {
+    pub fn phase(&self) -> Option<MoonPhase> {
+        self.moonphase.map(MoonPhase::from_degrees)
+    }
+}
+
+/// Rise or set of a body.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Event {
+    #[serde(default, deserialize_with = "deserialize_time")]
+    pub time: Option<DateTime<FixedOffset>>,
+    /// Direction in degrees, clockwise from north.
+    pub azimuth: Option<f64>,
+}
+
+/// Highest or lowest position of a body.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Culmination {
+    #[serde(default, deserialize_with = "deserialize_time")]
+    pub time: Option<DateTime<FixedOffset>>,
+    /// Elevation of the center of the disc above the horizon in degrees.
+    pub disc_centre_elevation: Option<f64>,
+    pub visible: Option<bool>,
+}
+
+/// One of the eight principal phases of the Moon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MoonPhase {
+    NewMoon,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    FullMoon,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+impl MoonPhase {
+    /// Returns the phase closest to the given angle (see [MoonProperties::moonphase]).
+    pub fn from_degrees(degrees: f64) -> Self {
+        const PHASES: [MoonPhase; 8] = [
+            MoonPhase::NewMoon,
+            MoonPhase::WaxingCrescent,
+            MoonPhase::FirstQuarter,
+            MoonPhase::WaxingGibbous,
+            MoonPhase::FullMoon,
+            MoonPhase::WaningGibbous,
+            MoonPhase::LastQuarter,
+            MoonPhase::WaningCrescent,
+        ];
+
+        PHASES[(degrees.rem_euclid(360.0) / 45.0).round() as usize % PHASES.len()]
+    }
+
+    /// Returns the name of the phase, e.g. "Waxing crescent".
+    pub fn description(&self) -> &'static str {
+        match self {
+            MoonPhase::NewMoon => "New moon",
+            MoonPhase::WaxingCrescent => "Waxing crescent",
+            MoonPhase::FirstQuarter => "First quarter",
+            MoonPhase::WaxingGibbous => "Waxing gibbous",
+            MoonPhase::FullMoon => "Full moon",
+            MoonPhase::WaningGibbous => "Waning gibbous",
+            MoonPhase::LastQuarter => "Last quarter",
+            MoonPhase::WaningCrescent => "Waning crescent",
+        }
+    }
+}
+
+impl fmt::Display for MoonPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+/// The times may come without seconds (e.g. "2023-03-22T06:10+01:00") which isn't valid
+/// RFC 3339.
+fn parse_time(value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M%#z"))
+        .ok()
+}
+
+fn deserialize_time<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<DateTime<FixedOffset>>, D::Error> {
+    let Some(value) = Option::<Cow<str>>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+
+    parse_time(&value)
+        .map(Some)
+        .ok_or_else(|| serde::de::Error::custom(format!("invalid time: {}", value)))
+}
+
+fn deserialize_interval<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<[DateTime<FixedOffset>; 2], D::Error> {
+    let [start, end] = <[Cow<str>; 2]>::deserialize(deserializer)?;
+
+    let parse = |value: &str| {
+        parse_time(value)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid time: {}", value)))
+    };
+
+    Ok([parse(&start)?, parse(&end)?])
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{FixedOffset, NaiveDate, TimeZone};
+    use url::Url;
+
+    use super::{parse_time, MoonBody, MoonPhase, Params, Request, SunBody};
+    use crate::client::Request as _;
+
+    #[test]
+    fn creates_query() {
+        let date = NaiveDate::from_ymd_opt(2023, 3, 22).unwrap();
+        let params = Params::new(50.0880, 14.4207, date, FixedOffset::east_opt(3600)).unwrap();
+        let request = Request::moon(params);
+
+        let mut url = Url::parse("https://api.met.no/weatherapi/")
+            .unwrap()
+            .join(request.path())
+            .unwrap();
+        url.query_pairs_mut().extend_pairs(request.query());
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.met.no/weatherapi/sunrise/3.0/moon?lat=50.088&lon=14.4207&date=2023-03-22&offset=%2B01%3A00"
+        );
+    }
+
+    #[test]
+    fn validates_params() {
+        let date = NaiveDate::from_ymd_opt(2023, 3, 22).unwrap();
+        assert!(Params::new(91.0, 14.4207, date, None).is_err());
+    }
+
+    #[test]
+    fn parses_times() {
+        let offset = FixedOffset::east_opt(3600).unwrap();
+        let expected = offset.with_ymd_and_hms(2023, 3, 22, 6, 10, 0).unwrap();
+
+        assert_eq!(parse_time("2023-03-22T06:10+01:00"), Some(expected));
+        assert_eq!(parse_time("2023-03-22T06:10:00+01:00"), Some(expected));
+        assert_eq!(parse_time("2023-03-22T05:10Z"), Some(expected));
+        assert_eq!(parse_time("06:10"), None);
+    }
+
+    #[test]
+    fn parses_sun_body() {
+        let body: SunBody = serde_json::from_str(
+            r#"{
+                "copyright": "MET Norway",
+                "licenseURL": "https://api.met.no/license_data.html",
+                "type": "Feature",
+                "geometry": { "type": "Point", "coordinates": [14.4207, 50.088] },
+                "when": { "interval": ["2023-03-21T23:08:00Z", "2023-03-22T23:12:00Z"] },
+                "properties": {
+                    "body": "Sun",
+                    "sunrise": { "time": "2023-03-22T05:57+01:00", "azimuth": 88.7 },
+                    "sunset": { "time": "2023-03-22T18:14+01:00", "azimuth": 271.6 },
+                    "solarnoon": {
+                        "time": "2023-03-22T12:06+01:00",
+                        "disc_centre_elevation": 39.8,
+                        "visible": true
+                    },
+                    "solarmidnight": {
+                        "time": "2023-03-22T00:06+01:00",
+                        "disc_centre_elevation": -40.3,
+                        "visible": false
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let offset = FixedOffset::east_opt(3600).unwrap();
+        assert_eq!(body.geometry.coordinates.latitude, 50.088);
+        assert_eq!(
+            body.properties.sunrise.unwrap().time,
+            Some(offset.with_ymd_and_hms(2023, 3, 22, 5, 57, 0).unwrap())
+        );
+        assert_eq!(body.properties.solarnoon.unwrap().visible, Some(true));
+    }
+
+    #[test]
+    fn parses_moon_body() {
+        let body: MoonBody = serde_json::from_str(
+            r#"{
+                "type": "Feature",
+                "geometry": { "type": "Point", "coordinates": [14.4207, 50.088] },
+                "when": { "interval": ["2023-03-21T23:08:00Z", "2023-03-22T23:12:00Z"] },
+                "properties": {
+                    "body": "Moon",
+                    "moonrise": { "time": "2023-03-22T06:34+01:00", "azimuth": 79.3 },
+                    "moonset": { "time": null, "azimuth": null },
+                    "high_moon": {
+                        "time": "2023-03-22T12:47+01:00",
+                        "disc_centre_elevation": 34.8,
+                        "visible": true
+                    },
+                    "low_moon": {
+                        "time": "2023-03-22T00:25+01:00",
+                        "disc_centre_elevation": -46.3,
+                        "visible": false
+                    },
+                    "moonphase": 2.7
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(body.properties.moonset.as_ref().unwrap().time, None);
+        assert_eq!(body.properties.phase(), Some(MoonPhase::NewMoon));
+    }
+
+    #[test]
+    fn converts_degrees_to_moon_phase() {
+        assert_eq!(MoonPhase::from_degrees(100.0), MoonPhase::FirstQuarter);
+        assert_eq!(MoonPhase::from_degrees(185.0), MoonPhase::FullMoon);
+        assert_eq!(MoonPhase::from_degrees(300.0), MoonPhase::WaningCrescent);
+        assert_eq!(MoonPhase::from_degrees(350.0), MoonPhase::NewMoon);
+    }
+}
@@ -0,0 +1,419 @@
+//! Human-written regional forecasts for Norway as provided by the [Textforecast API]. Use
+//! [Monsoon::get_text_forecasts] to list the available forecasts and [Monsoon::get_text_forecast]
+//! to fetch one of them.
+//!
+//! Example:
+//!
+//! ```no_run
+//! use monsoon::{textforecast, Monsoon};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+//! let monsoon = Monsoon::new("test.com support@test.com")?;
+//!
+//! let params = textforecast::Params::new("landoverview")?;
+//! let forecast = monsoon.get_text_forecast(params).await?.text_forecast()?;
+//!
+//! for section in forecast.sections.iter() {
+//!     if let Some(text) = section.text(textforecast::Language::Norwegian) {
+//!         println!("{} ({} - {}): {}", section.area.name, section.from, section.to, text);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [Textforecast API]: https://api.met.no/weatherapi/textforecast/2.0/documentation
+//! [Monsoon::get_text_forecasts]: crate::Monsoon::get_text_forecasts
+//! [Monsoon::get_text_forecast]: crate::Monsoon::get_text_forecast
+use chrono::{DateTime, FixedOffset, NaiveDateTime};
+use roxmltree::{Document, Node};
+
+use crate::{
+    client::Request,
+    xml::{child, children, text},
+    Error, Response, Result,
+};
+
+/// The forecast which should be fetched, e.g. "landoverview" or "sea_en". See
+/// [Monsoon::get_text_forecasts] for the list of the available forecasts.
+///
+/// [Monsoon::get_text_forecasts]: crate::Monsoon::get_text_forecasts
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Params {
+    pub forecast: Box<str>,
+
+    pub last_response: Option<Response>,
+}
+
+impl Params {
+    pub fn new(forecast: &str) -> Result<Self> {
+        Self::new_with_last_response(forecast, None)
+    }
+
+    pub fn new_with_last_response(
+        forecast: &str,
+        last_response: impl Into<Option<Response>>,
+    ) -> Result<Self> {
+        if forecast.is_empty()
+            || !forecast
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'_')
+        {
+            return Err(Error::Params("Invalid forecast value."));
+        }
+
+        Ok(Self {
+            forecast: forecast.into(),
+            last_response: last_response.into(),
+        })
+    }
+}
+
+impl Request for Params {
+    fn path(&self) -> &'static str {
+        "textforecast/2.0/"
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        vec![("forecast", self.forecast.to_string())]
+    }
+
+    fn last_response(&self) -> Option<&Response> {
+        self.last_response.as_ref()
+    }
+}
+
+/// Request for the list of the available forecasts.
+#[derive(Debug)]
+pub(crate) struct AvailableRequest;
+
+impl Request for AvailableRequest {
+    fn path(&self) -> &'static str {
+        "textforecast/2.0/available"
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    fn last_response(&self) -> Option<&Response> {
+        None
+    }
+}
+
+impl Response {
+    /// Parses the body of a response from [Monsoon::get_text_forecast].
+    ///
+    /// [Monsoon::get_text_forecast]: crate::Monsoon::get_text_forecast
+    pub fn text_forecast(&self) -> Result<TextForecast> {
+        TextForecast::parse(&self.raw_body)
+    }
+
+    /// Parses the body of a response from [Monsoon::get_text_forecasts].
+    ///
+    /// [Monsoon::get_text_forecasts]: crate::Monsoon::get_text_forecasts
+    pub fn text_forecasts(&self) -> Result<Box<[AvailableForecast]>> {
+        AvailableForecast::parse(&self.raw_body)
+    }
+}
+
+/// Forecast listed by [Monsoon::get_text_forecasts].
+///
+/// [Monsoon::get_text_forecasts]: crate::Monsoon::get_text_forecasts
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct AvailableForecast {
+    /// Name of the forecast to be used in [Params::new].
+    pub name: Box<str>,
+    pub uri: Option<Box<str>>,
+}
+
+impl AvailableForecast {
+    fn parse(raw: &str) -> Result<Box<[Self]>> {
+        let document = Document::parse(raw)?;
+
+        Ok(document
+            .root_element()
+            .descendants()
+            .filter(|node| node.has_tag_name("query"))
+            .filter_map(|query| {
+                let name = children(query, "parameter")
+                    .find(|parameter| parameter.attribute("name") == Some("forecast"))?
+                    .attribute("value")?;
+
+                Some(Self {
+                    name: name.into(),
+                    uri: text(query, "uri").map(Into::into),
+                })
+            })
+            .collect())
+    }
+}
+
+/// Language of the forecast texts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    Norwegian,
+    English,
+}
+
+impl Language {
+    fn matches(&self, code: &str) -> bool {
+        let code = code.split(['-', '_']).next().unwrap_or_default();
+
+        match self {
+            Language::Norwegian => matches!(code, "no" | "nb" | "nn"),
+            Language::English => code == "en",
+        }
+    }
+}
+
+/// The forecast split into sections, one per area and time period.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TextForecast {
+    pub sections: Box<[Section]>,
+}
+
+impl TextForecast {
+    fn parse(raw: &str) -> Result<Self> {
+        let document = Document::parse(raw)?;
+
+        let mut sections = Vec::new();
+        for time in children(document.root_element(), "time") {
+            let from = parse_time(attribute(time, "from")?)?;
+            let to = parse_time(attribute(time, "to")?)?;
+
+            for forecast_type in children(time, "forecasttype") {
+                let name = forecast_type.attribute("name").unwrap_or_default();
+
+                let areas = forecast_type
+                    .children()
+                    .filter(|node| node.has_tag_name("location") || node.has_tag_name("area"));
+
+                for area in areas {
+                    sections.push(Section {
+                        forecast_type: name.into(),
+                        area: ForecastArea::parse(area)?,
+                        from,
+                        to,
+                        texts: Text::parse(area),
+                    });
+                }
+            }
+        }
+
+        Ok(Self {
+            sections: sections.into(),
+        })
+    }
+
+    /// Returns the areas the forecast is available for, in the order of their first appearance.
+    pub fn areas(&self) -> Vec<&ForecastArea> {
+        let mut areas: Vec<&ForecastArea> = Vec::new();
+
+        for section in self.sections.iter() {
+            if !areas.contains(&&section.area) {
+                areas.push(&section.area);
+            }
+        }
+
+        areas
+    }
+}
+
+/// Forecast for a single area and time period.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Section {
+    pub forecast_type: Box<str>,
+    pub area: ForecastArea,
+    pub from: DateTime<FixedOffset>,
+    pub to: DateTime<FixedOffset>,
+    pub texts: Box<[Text]>,
+}
+
+impl Section {
+    /// Returns the text in the given language. Falls back to the text without a specified
+    /// language, if there's any.
+    pub fn text(&self, language: Language) -> Option<&str> {
+        self.texts
+            .iter()
+            .find(|text| {
+                text.language
+                    .as_deref()
+                    .is_some_and(|code| language.matches(code))
+            })
+            .or_else(|| self.texts.iter().find(|text| text.language.is_none()))
+            .map(|text| &*text.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ForecastArea {
+    pub id: Option<Box<str>>,
+    pub name: Box<str>,
+}
+
+impl ForecastArea {
+    fn parse(area: Node) -> Result<Self> {
+        let name = area
+            .attribute("name")
+            .or_else(|| area.attribute("areaDesc"))
+            .ok_or(Error::Response("Missing area name.".into()))?;
+
+        Ok(Self {
+            id: area
+                .attribute("id")
+                .or_else(|| area.attribute("areaId"))
+                .map(Into::into),
+            name: name.into(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Text {
+    /// Language code, e.g. "nb" or "en".
+    pub language: Option<Box<str>>,
+    pub value: Box<str>,
+}
+
+impl Text {
+    /// The text is either split into `in` elements per language or contained directly in the
+    /// area element.
+    fn parse(area: Node) -> Box<[Self]> {
+        let texts = children(area, "in")
+            .map(|node| Self {
+                language: language(node).map(Into::into),
+                value: content(node).into(),
+            })
+            .collect::<Vec<_>>();
+
+        if !texts.is_empty() || child(area, "in").is_some() {
+            return texts.into();
+        }
+
+        let value = content(area);
+        if value.is_empty() {
+            return Box::default();
+        }
+
+        Box::new([Self {
+            language: language(area).map(Into::into),
+            value: value.into(),
+        }])
+    }
+}
+
+fn language<'a>(node: Node<'a, '_>) -> Option<&'a str> {
+    node.attribute(("http://www.w3.org/XML/1998/namespace", "lang"))
+        .or_else(|| node.attribute("lang"))
+}
+
+/// Joins the paragraphs of the text.
+fn content(node: Node) -> String {
+    node.descendants()
+        .filter(|node| node.is_text())
+        .filter_map(|node| node.text())
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn attribute<'a>(node: Node<'a, '_>, name: &str) -> Result<&'a str> {
+    node.attribute(name)
+        .ok_or_else(|| Error::Response(format!("Missing {} attribute.", name).into()))
+}
+
+/// The times without an offset are in UTC.
+fn parse_time(value: &str) -> Result<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| {
+            NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
+                .map(|time| time.and_utc().fixed_offset())
+        })
+        .map_err(|_| Error::Response(format!("Invalid time: {}", value).into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+
+    use super::{AvailableForecast, Language, Params, TextForecast};
+    use crate::{client::Request, Error};
+
+    #[test]
+    fn creates_query() {
+        let params = Params::new("sea_en").unwrap();
+
+        assert_eq!(params.path(), "textforecast/2.0/");
+        assert_eq!(params.query(), [("forecast", "sea_en".to_string())]);
+        assert!(matches!(Params::new("land&x=1"), Err(Error::Params(_))));
+    }
+
+    #[test]
+    fn parses_forecast() {
+        let forecast = TextForecast::parse(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <textforecast>
+                <meta><licenseurl>https://api.met.no/license_data.html</licenseurl></meta>
+                <time from="2023-03-21T06:00:00" to="2023-03-22T00:00:00+01:00">
+                    <forecasttype name="landoverview">
+                        <location name="Østlandet" id="0503">
+                            <in xml:lang="nb">Skyet. <p>Lett regn.</p></in>
+                            <in xml:lang="en">Cloudy. <p>Light rain.</p></in>
+                        </location>
+                        <area areaDesc="Vestlandet" areaId="0504">Oppholdsvær.</area>
+                    </forecasttype>
+                </time>
+            </textforecast>"#,
+        )
+        .unwrap();
+
+        let section = &forecast.sections[0];
+        assert_eq!(&*section.area.name, "Østlandet");
+        assert_eq!(section.area.id.as_deref(), Some("0503"));
+        assert_eq!(
+            section.from,
+            DateTime::parse_from_rfc3339("2023-03-21T06:00:00Z").unwrap()
+        );
+        assert_eq!(
+            section.text(Language::English),
+            Some("Cloudy.\nLight rain.")
+        );
+        assert_eq!(
+            section.text(Language::Norwegian),
+            Some("Skyet.\nLett regn.")
+        );
+
+        let section = &forecast.sections[1];
+        assert_eq!(&*section.area.name, "Vestlandet");
+        assert_eq!(section.text(Language::English), Some("Oppholdsvær."));
+
+        assert_eq!(forecast.areas().len(), 2);
+    }
+
+    #[test]
+    fn parses_available_forecasts() {
+        let available = AvailableForecast::parse(
+            r#"<available>
+                <query>
+                    <parameter name="forecast" value="landoverview"/>
+                    <uri>https://api.met.no/weatherapi/textforecast/2.0/?forecast=landoverview</uri>
+                </query>
+                <query><parameter name="forecast" value="sea_en"/></query>
+            </available>"#,
+        )
+        .unwrap();
+
+        assert_eq!(available.len(), 2);
+        assert_eq!(&*available[1].name, "sea_en");
+        assert_eq!(available[1].uri, None);
+    }
+}
@@ -0,0 +1,392 @@
+//! Observed and predicted water levels in the Norwegian harbors as provided by the
+//! [Tidal water API]. Use [Harbor::find] or [Harbor::ALL] to look up the harbor and
+//! [Monsoon::get_tidal_water] to fetch the data.
+//!
+//! Example:
+//!
+//! ```no_run
+//! use monsoon::{tidalwater, Monsoon};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+//! let monsoon = Monsoon::new("test.com support@test.com")?;
+//!
+//! let harbor = tidalwater::Harbor::find("Bergen").unwrap();
+//! let params = tidalwater::Params::new(harbor);
+//! let body = monsoon.get_tidal_water(params).await?.tidal_water()?;
+//!
+//! for entry in body.entries.iter().filter(|entry| entry.is_prediction()) {
+//!     println!("{}: {:?} m", entry.time, entry.total);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [Tidal water API]: https://api.met.no/weatherapi/tidalwater/1.1/documentation
+//! [Monsoon::get_tidal_water]: crate::Monsoon::get_tidal_water
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::{client::Request, Error, Response, Result};
+
+/// Harbor for which the water levels are available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Harbor {
+    /// Identifier used by the API, e.g. "bodo".
+    pub id: &'static str,
+    pub name: &'static str,
+}
+
+impl Harbor {
+    pub const ALL: &'static [Harbor] = &[
+        Harbor::new("andenes", "Andenes"),
+        Harbor::new("bergen", "Bergen"),
+        Harbor::new("bodo", "Bodø"),
+        Harbor::new("bruravik", "Bruravik"),
+        Harbor::new("hammerfest", "Hammerfest"),
+        Harbor::new("harstad", "Harstad"),
+        Harbor::new("heimsjo", "Heimsjø"),
+        Harbor::new("helgeroa", "Helgeroa"),
+        Harbor::new("honningsvag", "Honningsvåg"),
+        Harbor::new("kabelvag", "Kabelvåg"),
+        Harbor::new("kristiansund", "Kristiansund"),
+        Harbor::new("maloy", "Måløy"),
+        Harbor::new("mausund", "Mausund"),
+        Harbor::new("narvik", "Narvik"),
+        Harbor::new("ny-alesund", "Ny-Ålesund"),
+        Harbor::new("oscarsborg", "Oscarsborg"),
+        Harbor::new("oslo", "Oslo"),
+        Harbor::new("rorvik", "Rørvik"),
+        Harbor::new("sirevag", "Sirevåg"),
+        Harbor::new("stavanger", "Stavanger"),
+        Harbor::new("tregde", "Tregde"),
+        Harbor::new("tromso", "Tromsø"),
+        Harbor::new("trondheim", "Trondheim"),
+        Harbor::new("vardo", "Vardø"),
+        Harbor::new("viker", "Viker"),
+        Harbor::new("alesund", "Ålesund"),
+    ];
+
+    const fn new(id: &'static str, name: &'static str) -> Self {
+        Self { id, name }
+    }
+
+    /// Looks up the harbor by its identifier or name, ignoring the case.
+    pub fn find(id_or_name: &str) -> Option<Harbor> {
+        let id_or_name = id_or_name.trim().to_lowercase();
+
+        Self::ALL
+            .iter()
+            .find(|harbor| harbor.id == id_or_name || harbor.name.to_lowercase() == id_or_name)
+            .copied()
+    }
+}
+
+/// The harbor for which the water levels should be looked up.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Params {
+    pub harbor: Harbor,
+
+    pub last_response: Option<Response>,
+}
+
+impl Params {
+    pub fn new(harbor: Harbor) -> Self {
+        Self::new_with_last_response(harbor, None)
+    }
+
+    pub fn new_with_last_response(
+        harbor: Harbor,
+        last_response: impl Into<Option<Response>>,
+    ) -> Self {
+        Self {
+            harbor,
+            last_response: last_response.into(),
+        }
+    }
+}
+
+impl Request for Params {
+    fn path(&self) -> &'static str {
+        "tidalwater/1.1/"
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("harbor", self.harbor.id.to_string()),
+            ("content_type", "text/plain".to_string()),
+        ]
+    }
+
+    fn last_response(&self) -> Option<&Response> {
+        self.last_response.as_ref()
+    }
+}
+
+impl Response {
+    /// Parses the body of a response from [Monsoon::get_tidal_water].
+    ///
+    /// [Monsoon::get_tidal_water]: crate::Monsoon::get_tidal_water
+    pub fn tidal_water(&self) -> Result<TidalWater> {
+        TidalWater::parse(&self.raw_body)
+    }
+}
+
+/// The water levels in a harbor. All of the values are in meters.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TidalWater {
+    pub entries: Box<[Entry]>,
+}
+
+/// Water level at a single point in time. The observed level is only available for the past.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Entry {
+    pub time: DateTime<Utc>,
+    pub observation: Option<f64>,
+    /// Deviation from the astronomical tide caused by the weather.
+    pub surge: Option<f64>,
+    /// The astronomical tide.
+    pub tide: Option<f64>,
+    /// The sum of the surge and the tide.
+    pub total: Option<f64>,
+    pub percentiles: Option<Percentiles>,
+}
+
+impl Entry {
+    pub fn is_prediction(&self) -> bool {
+        self.observation.is_none()
+    }
+}
+
+/// Spread of the predicted total water level across the ensemble.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Percentiles {
+    pub p0: f64,
+    pub p25: f64,
+    pub p50: f64,
+    pub p75: f64,
+    pub p100: f64,
+}
+
+/// Columns of the table. The names differ between the Norwegian and the English version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Column {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Observation,
+    Surge,
+    Tide,
+    Total,
+    Percentile(u8),
+    Other,
+}
+
+impl Column {
+    fn parse(name: &str) -> Self {
+        match name.to_uppercase().as_str() {
+            "AAR" | "ÅR" | "YEAR" | "YYYY" => Column::Year,
+            "MND" | "MONTH" | "MM" => Column::Month,
+            "DAG" | "DAY" | "DD" => Column::Day,
+            "TIM" | "HOUR" | "HH" => Column::Hour,
+            "OBS" => Column::Observation,
+            "SURGE" => Column::Surge,
+            "TIDE" | "TIDEVANN" => Column::Tide,
+            "TOTAL" => Column::Total,
+            name => match name.strip_suffix('P').and_then(|value| value.parse().ok()) {
+                Some(percentile @ (0 | 25 | 50 | 75 | 100)) => Column::Percentile(percentile),
+                _ => Column::Other,
+            },
+        }
+    }
+}
+
+impl TidalWater {
+    /// The body is a fixed-width table preceded by comments. The numbers are aligned to the right
+    /// with their column names so each value is read from the end of the previous column up to
+    /// the end of its own one. This keeps the columns aligned even when some values are missing.
+    fn parse(raw: &str) -> Result<Self> {
+        let mut columns: Vec<(usize, Column)> = Vec::new();
+        let mut entries = Vec::new();
+
+        for line in raw.lines() {
+            let content = line.trim_start_matches('#');
+            let offset = line.len() - content.len();
+
+            if columns.is_empty() {
+                if content
+                    .split_whitespace()
+                    .next()
+                    .is_some_and(|name| Column::parse(name) == Column::Year)
+                {
+                    columns = header_columns(content)
+                        .map(|(end, column)| (offset + end, column))
+                        .collect();
+                }
+
+                continue;
+            }
+
+            if line.starts_with('#') || line.trim().is_empty() {
+                continue;
+            }
+
+            entries.push(Entry::parse(line, &columns)?);
+        }
+
+        if columns.is_empty() {
+            return Err(Error::Response("Missing table header.".into()));
+        }
+
+        Ok(Self {
+            entries: entries.into(),
+        })
+    }
+}
+
+/// Returns the end of each column name along with the column.
+fn header_columns(header: &str) -> impl Iterator<Item = (usize, Column)> + '_ {
+    header
+        .char_indices()
+        .filter(|(_, char)| !char.is_whitespace())
+        .filter(|&(index, _)| {
+            header[..index]
+                .chars()
+                .next_back()
+                .is_none_or(char::is_whitespace)
+        })
+        .map(|(start, _)| {
+            let name = header[start..]
+                .split_whitespace()
+                .next()
+                .unwrap_or_default();
+            (start + name.len(), Column::parse(name))
+        })
+}
+
+impl Entry {
+    fn parse(line: &str, columns: &[(usize, Column)]) -> Result<Self> {
+        let invalid = || Error::Response(format!("Invalid tidal water entry: {}", line).into());
+
+        let mut date = [None; 4];
+        let mut entry = Entry {
+            time: DateTime::default(),
+            observation: None,
+            surge: None,
+            tide: None,
+            total: None,
+            percentiles: None,
+        };
+        let mut percentiles = [None; 5];
+
+        let mut start = 0;
+        for &(end, column) in columns {
+            let value = line
+                .get(start..end.min(line.len()))
+                .unwrap_or_default()
+                .trim();
+            start = end;
+
+            if value.is_empty() {
+                continue;
+            }
+
+            let number = || value.parse::<f64>().ok().filter(|value| value.is_finite());
+            match column {
+                Column::Year => date[0] = value.parse().ok(),
+                Column::Month => date[1] = value.parse().ok(),
+                Column::Day => date[2] = value.parse().ok(),
+                Column::Hour => date[3] = value.parse().ok(),
+                Column::Observation => entry.observation = number(),
+                Column::Surge => entry.surge = number(),
+                Column::Tide => entry.tide = number(),
+                Column::Total => entry.total = number(),
+                Column::Percentile(percentile) => {
+                    percentiles[percentile as usize / 25] = number();
+                }
+                Column::Other => {}
+            }
+        }
+
+        let [Some(year), Some(month), Some(day), Some(hour)] = date else {
+            return Err(invalid());
+        };
+
+        entry.time = NaiveDate::from_ymd_opt(year as i32, month, day)
+            .and_then(|date| date.and_hms_opt(hour, 0, 0))
+            .ok_or_else(invalid)?
+            .and_utc();
+
+        if let [Some(p0), Some(p25), Some(p50), Some(p75), Some(p100)] = percentiles {
+            entry.percentiles = Some(Percentiles {
+                p0,
+                p25,
+                p50,
+                p75,
+                p100,
+            });
+        }
+
+        Ok(entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::{Harbor, Params, TidalWater};
+    use crate::client::Request;
+
+    #[test]
+    fn finds_harbors() {
+        assert_eq!(Harbor::find("bodo").unwrap().name, "Bodø");
+        assert_eq!(Harbor::find("TROMSØ").unwrap().id, "tromso");
+        assert_eq!(Harbor::find("Prague"), None);
+
+        let params = Params::new(Harbor::find("Ny-Ålesund").unwrap());
+        assert_eq!(params.path(), "tidalwater/1.1/");
+        assert_eq!(params.query()[0], ("harbor", "ny-alesund".to_string()));
+    }
+
+    #[test]
+    fn parses_table() {
+        let body = TidalWater::parse(concat!(
+            "# Vannstand for Bergen\n",
+            "# Alle tall er i meter\n",
+            "#\n",
+            "#  AAR MND DAG TIM PROG   OBS SURGE  TIDE TOTAL    0p   25p   50p   75p  100p\n",
+            "  2023   3  21   9    0  0.53  0.06  0.47  0.53\n",
+            "  2023   3  21  10    1        0.08  0.52  0.60  0.55  0.58  0.60  0.62  0.66\n",
+        ))
+        .unwrap();
+
+        assert_eq!(body.entries.len(), 2);
+
+        let observation = &body.entries[0];
+        assert_eq!(
+            observation.time,
+            Utc.with_ymd_and_hms(2023, 3, 21, 9, 0, 0).unwrap()
+        );
+        assert_eq!(observation.observation, Some(0.53));
+        assert_eq!(observation.tide, Some(0.47));
+        assert!(!observation.is_prediction());
+        assert_eq!(observation.percentiles, None);
+
+        let prediction = &body.entries[1];
+        assert!(prediction.is_prediction());
+        assert_eq!(prediction.surge, Some(0.08));
+        assert_eq!(prediction.total, Some(0.6));
+        assert_eq!(prediction.percentiles.unwrap().p25, 0.58);
+        assert_eq!(prediction.percentiles.unwrap().p100, 0.66);
+    }
+
+    #[test]
+    fn rejects_body_without_header() {
+        assert!(TidalWater::parse("# Nothing here\n").is_err());
+    }
+}
@@ -0,0 +1,176 @@
+//! The HTTP layer used to send the requests. See [MonsoonBuilder::transport].
+//!
+//! [MonsoonBuilder::transport]: crate::MonsoonBuilder::transport
+use std::{fmt::Debug, future::Future, pin::Pin};
+
+pub use http::{HeaderMap, StatusCode};
+pub use url::Url;
+
+/// Error returned by a [Transport]. Return [std::io::Error] with a kind such as
+/// [std::io::ErrorKind::ConnectionReset] or [std::io::ErrorKind::TimedOut] for failures which
+/// may be retried (see [Error::is_retryable]).
+///
+/// [Error::is_retryable]: crate::Error::is_retryable
+pub type TransportError = Box<dyn std::error::Error + Send + Sync>;
+
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<TransportResponse, TransportError>> + Send + 'a>>;
+
+/// Sends the GET requests. The headers include the "User-Agent" and possibly the
+/// "If-Modified-Since" and are expected to be sent as they are. Responses with any status code
+/// should be returned as they are, an error is only expected if no response was received at all.
+/// Implemented by [ReqwestTransport] which is used by default.
+///
+/// Example:
+///
+/// ```
+/// use monsoon::{
+///     transport::{HeaderMap, StatusCode, Transport, TransportFuture, TransportResponse, Url},
+///     Monsoon,
+/// };
+///
+/// #[derive(Debug)]
+/// struct Offline;
+///
+/// impl Transport for Offline {
+///     fn get(&self, _url: Url, _headers: HeaderMap) -> TransportFuture<'_> {
+///         Box::pin(async {
+///             Ok(TransportResponse::new(
+///                 StatusCode::SERVICE_UNAVAILABLE,
+///                 HeaderMap::new(),
+///                 Vec::new(),
+///             ))
+///         })
+///     }
+/// }
+///
+/// let monsoon = Monsoon::builder("test.com support@test.com")
+///     .transport(Offline)
+///     .build();
+/// ```
+pub trait Transport: Debug + Send + Sync {
+    fn get(&self, url: Url, headers: HeaderMap) -> TransportFuture<'_>;
+}
+
+/// Response received by a [Transport].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct TransportResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl TransportResponse {
+    pub fn new(status: StatusCode, headers: HeaderMap, body: Vec<u8>) -> Self {
+        Self {
+            status,
+            headers,
+            body,
+        }
+    }
+}
+
+/// [Transport] backed by [reqwest]. Requires the `reqwest` feature (enabled by default).
+#[cfg(feature = "reqwest")]
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "reqwest")]
+impl ReqwestTransport {
+    pub fn new() -> Result<Self, TransportError> {
+        Ok(Self {
+            client: reqwest::Client::builder().build()?,
+        })
+    }
+}
+
+/// Uses the given client, e.g. one configured with a proxy.
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Client> for ReqwestTransport {
+    fn from(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl Transport for ReqwestTransport {
+    fn get(&self, url: Url, headers: HeaderMap) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let response = self.client.get(url).headers(headers).send().await?;
+
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?.to_vec();
+
+            Ok(TransportResponse::new(status, headers, body))
+        })
+    }
+}
+
+/// [Transport] for tests serving canned responses.
+#[cfg(test)]
+pub(crate) mod fake {
+    use std::{
+        collections::VecDeque,
+        sync::{Arc, Mutex},
+    };
+
+    use super::{HeaderMap, StatusCode, Transport, TransportFuture, TransportResponse, Url};
+
+    /// Serves the queued responses in order and records the requests. Clones share the state so
+    /// that one can be handed over to the client while the other is inspected.
+    #[derive(Debug, Clone, Default)]
+    pub(crate) struct FakeTransport {
+        responses: Arc<Mutex<VecDeque<TransportResponse>>>,
+        requests: Arc<Mutex<Vec<(Url, HeaderMap)>>>,
+    }
+
+    impl FakeTransport {
+        /// Queues a response. See [headers] for the headers required by the client.
+        pub(crate) fn respond(self, status: StatusCode, headers: HeaderMap, body: &str) -> Self {
+            self.responses
+                .lock()
+                .unwrap()
+                .push_back(TransportResponse::new(status, headers, body.into()));
+            self
+        }
+
+        /// Returns the URLs and headers of the requests sent so far.
+        pub(crate) fn requests(&self) -> Vec<(Url, HeaderMap)> {
+            self.requests.lock().unwrap().clone()
+        }
+
+        pub(crate) fn calls(&self) -> usize {
+            self.requests.lock().unwrap().len()
+        }
+    }
+
+    impl Transport for FakeTransport {
+        fn get(&self, url: Url, headers: HeaderMap) -> TransportFuture<'_> {
+            self.requests.lock().unwrap().push((url, headers));
+            let response = self
+                .responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("no response queued");
+
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    /// Returns the "expires" header with the given value and a fixed "last-modified" header of
+    /// "Tue, 21 Mar 2023 09:00:00 GMT".
+    pub(crate) fn headers(expires: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("expires", expires.parse().unwrap());
+        headers.insert(
+            "last-modified",
+            "Tue, 21 Mar 2023 09:00:00 GMT".parse().unwrap(),
+        );
+        headers
+    }
+}
@@ -0,0 +1,24 @@
+use roxmltree::Node;
+
+use crate::{Error, Result};
+
+pub(crate) fn children<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    name: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    node.children()
+        .filter(move |child| child.tag_name().name() == name)
+}
+
+pub(crate) fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children()
+        .find(|child| child.tag_name().name() == name)
+}
+
+pub(crate) fn text<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    child(node, name)?.text().map(str::trim)
+}
+
+pub(crate) fn required<'a>(node: Node<'a, '_>, name: &str) -> Result<&'a str> {
+    text(node, name).ok_or_else(|| Error::Response(format!("Missing {} element.", name).into()))
+}